@@ -125,7 +125,7 @@ impl Service {
             if receiver_seq.is_none() {
                 let builder = if params.is_designated_dealer.unwrap_or(false) {
                     self.transaction_factory.create_designated_dealer(
-                        params.currency_code,
+                        params.currency_code.clone(),
                         0, // sliding_nonce
                         params.auth_key,
                         &format!("No. {} DD", treasury_account.sequence_number()),
@@ -133,7 +133,7 @@ impl Service {
                     )
                 } else {
                     self.transaction_factory.create_parent_vasp_account(
-                        params.currency_code,
+                        params.currency_code.clone(),
                         0, // sliding_nonce
                         params.auth_key,
                         &format!("No. {} VASP", treasury_account.sequence_number()),
@@ -163,7 +163,7 @@ impl Service {
 
             txns.push(dd_account.sign_with_transaction_builder(
                 self.transaction_factory.peer_to_peer_with_metadata(
-                    params.currency_code,
+                    params.currency_code.clone(),
                     params.receiver(),
                     params.amount,
                     params.bcs_metadata(),
@@ -9,7 +9,8 @@
 #![forbid(unsafe_code)]
 
 use diem_crypto::{ed25519::Ed25519PrivateKey, traits::SigningKey, PrivateKey, Uniform};
-use diem_transaction_builder::stdlib::*;
+use diem_framework_releases::legacy::transaction_scripts::LegacyStdlibScript;
+use diem_transaction_builder::{stdlib::*, stdlib_script::StdlibScript};
 use diem_types::{
     account_address::AccountAddress,
     account_config,
@@ -1261,3 +1262,27 @@ fn add_child_currencies() {
     }
     }
 }
+
+#[test]
+fn every_legacy_script_has_a_builder() {
+    let mut missing = vec![];
+    for legacy in LegacyStdlibScript::all() {
+        match StdlibScript::all()
+            .iter()
+            .find(|script| script.name() == legacy.name())
+        {
+            Some(script) => assert_eq!(
+                script.compiled_bytes(),
+                legacy.compiled_bytes().into_vec().as_slice(),
+                "Builder for {} encodes stale bytecode",
+                legacy.name()
+            ),
+            None => missing.push(legacy.name()),
+        }
+    }
+    assert!(
+        missing.is_empty(),
+        "Scripts without a typed builder: {}",
+        missing.join(", ")
+    );
+}
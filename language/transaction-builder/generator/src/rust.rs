@@ -709,7 +709,7 @@ fn decode_{}_argument(arg: TransactionArgument) -> Option<{}> {{
     fn output_code_constant(&mut self, abi: &TransactionScriptABI) -> Result<()> {
         writeln!(
             self.out,
-            "\nconst {}_CODE: &[u8] = &[{}];",
+            "\npub const {}_CODE: &[u8] = &[{}];",
             abi.name().to_shouty_snake_case(),
             abi.code()
                 .iter()
@@ -229,7 +229,7 @@ fn fund_account() -> Result<()> {
     let amount = 1000;
     let currency = Currency::XUS;
     env.coffer()
-        .fund(currency, account.authentication_key(), amount)?;
+        .fund(currency.clone(), account.authentication_key(), amount)?;
 
     let account_view = client.get_account(account.address())?.into_inner().unwrap();
     let balance = account_view
@@ -291,14 +291,14 @@ fn create_child_account() -> Result<()> {
     let amount = 1000;
     let currency = Currency::XUS;
     env.coffer()
-        .fund(currency, account.authentication_key(), amount)?;
+        .fund(currency.clone(), account.authentication_key(), amount)?;
 
     let child_account = env.random_account();
 
     // create a child account
     let txn =
         account.sign_with_transaction_builder(env.transaction_factory().create_child_vasp_account(
-            currency,
+            currency.clone(),
             child_account.authentication_key(),
             false,
             0,
@@ -332,7 +332,7 @@ fn add_currency_to_account() -> Result<()> {
 
     let mut account = env.random_account();
     env.coffer()
-        .fund(currency, account.authentication_key(), amount)?;
+        .fund(currency.clone(), account.authentication_key(), amount)?;
 
     // Ensure that the account doesn't carry a balance of XDX
     let account_view = client.get_account(account.address())?.into_inner().unwrap();
@@ -375,14 +375,17 @@ fn peer_to_peer() -> Result<()> {
 
     let mut account = env.random_account();
     env.coffer()
-        .fund(currency, account.authentication_key(), start_amount)?;
+        .fund(currency.clone(), account.authentication_key(), start_amount)?;
 
     let account_2 = env.random_account();
-    env.coffer()
-        .fund(currency, account_2.authentication_key(), start_amount)?;
+    env.coffer().fund(
+        currency.clone(),
+        account_2.authentication_key(),
+        start_amount,
+    )?;
 
     let txn = account.sign_with_transaction_builder(env.transaction_factory().peer_to_peer(
-        currency,
+        currency.clone(),
         account_2.address(),
         transfer_amount,
     ));
@@ -483,15 +486,18 @@ fn dual_attestation() -> Result<()> {
 
     let mut account = env.random_account();
     env.coffer()
-        .fund(currency, account.authentication_key(), start_amount)?;
+        .fund(currency.clone(), account.authentication_key(), start_amount)?;
 
     let mut account_2 = env.random_account();
-    env.coffer()
-        .fund(currency, account_2.authentication_key(), start_amount)?;
+    env.coffer().fund(
+        currency.clone(),
+        account_2.authentication_key(),
+        start_amount,
+    )?;
 
     // Sending a txn that's over the dual_attestation_limit without a signature from the reciever
     let txn = account.sign_with_transaction_builder(env.transaction_factory().peer_to_peer(
-        currency,
+        currency.clone(),
         account_2.address(),
         transfer_amount,
     ));
@@ -532,7 +538,7 @@ fn dual_attestation() -> Result<()> {
     // Send a p2p txn with the signed metadata
     let txn = account.sign_with_transaction_builder(
         env.transaction_factory().peer_to_peer_with_metadata(
-            currency,
+            currency.clone(),
             account_2.address(),
             transfer_amount,
             metadata.as_bytes().to_vec(),
@@ -582,14 +588,14 @@ fn no_dual_attestation_needed_between_parent_vasp_and_its_child() -> Result<()>
 
     let mut account = env.random_account();
     env.coffer()
-        .fund(currency, account.authentication_key(), start_amount)?;
+        .fund(currency.clone(), account.authentication_key(), start_amount)?;
 
     let child_account = env.random_account();
 
     // create a child account
     let txn =
         account.sign_with_transaction_builder(env.transaction_factory().create_child_vasp_account(
-            currency,
+            currency.clone(),
             child_account.authentication_key(),
             false,
             0,
@@ -600,7 +606,7 @@ fn no_dual_attestation_needed_between_parent_vasp_and_its_child() -> Result<()>
 
     // Send a p2p txn over the dual_attestation_limit
     let txn = account.sign_with_transaction_builder(env.transaction_factory().peer_to_peer(
-        currency,
+        currency.clone(),
         child_account.address(),
         transfer_amount,
     ));
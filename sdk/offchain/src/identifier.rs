@@ -48,7 +48,11 @@ impl Intent {
             Ok(encoded_account) => encoded_account,
             Err(error) => return Err(IntentIdentifierError::Bech32(error)),
         };
-        Ok(encode_intent(&encoded_account, self.currency, self.amount))
+        Ok(encode_intent(
+            &encoded_account,
+            self.currency.clone(),
+            self.amount,
+        ))
     }
 
     pub fn account_address(&self) -> &AccountAddress {
@@ -60,7 +64,7 @@ impl Intent {
     }
 
     pub fn currency(&self) -> Option<Currency> {
-        self.currency
+        self.currency.clone()
     }
 
     pub fn amount(&self) -> Option<u64> {
@@ -2,17 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    move_types::{account_address::AccountAddress, language_storage::TypeTag},
+    move_types::account_address::AccountAddress,
     types::{
-        account_config::{gas_type_tag, xus_tag, GAS_NAME, XUS_NAME},
         chain_id::ChainId,
         transaction::{authenticator::AuthenticationKey, RawTransaction, TransactionPayload},
     },
 };
-use serde::{Deserialize, Serialize};
-use std::fmt;
 
-pub use diem_transaction_builder::stdlib;
+pub use diem_transaction_builder::{currencies::Currency, dual_attestation, stdlib};
 use diem_types::transaction::Script;
 
 pub struct TransactionBuilder {
@@ -498,7 +495,7 @@ impl TransactionFactory {
             payload,
             max_gas_amount: self.max_gas_amount,
             gas_unit_price: self.gas_unit_price,
-            gas_currency_code: self.gas_currency.into(),
+            gas_currency_code: self.gas_currency.as_str().to_owned(),
             expiration_timestamp_secs: self.expiration_timestamp(),
             chain_id: self.chain_id,
         }
@@ -522,11 +519,8 @@ pub struct DualAttestationMessage {
 }
 
 impl DualAttestationMessage {
-    pub fn new<M: Into<Vec<u8>>>(metadata: M, reciever: AccountAddress, amount: u64) -> Self {
-        let mut message = metadata.into();
-        bcs::serialize_into(&mut message, &reciever).unwrap();
-        bcs::serialize_into(&mut message, &amount).unwrap();
-        message.extend(b"@@$$DIEM_ATTEST$$@@");
+    pub fn new<M: Into<Vec<u8>>>(metadata: M, payer: AccountAddress, amount: u64) -> Self {
+        let message = dual_attestation::dual_attestation_message(payer, &metadata.into(), amount);
 
         Self {
             message: message.into(),
@@ -537,62 +531,3 @@ impl DualAttestationMessage {
         &self.message
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
-pub enum Currency {
-    XDX,
-    XUS,
-}
-
-impl Currency {
-    pub fn as_str(&self) -> &str {
-        match self {
-            Currency::XDX => GAS_NAME, //////// 0L ////////
-            Currency::XUS => XUS_NAME,
-        }
-    }
-
-    pub fn type_tag(&self) -> TypeTag {
-        match self {
-            Currency::XDX => gas_type_tag(), //////// 0L ////////
-            Currency::XUS => xus_tag(),
-        }
-    }
-}
-
-impl PartialEq<str> for Currency {
-    fn eq(&self, other: &str) -> bool {
-        self.as_str().eq(other)
-    }
-}
-
-impl PartialEq<Currency> for str {
-    fn eq(&self, other: &Currency) -> bool {
-        other.as_str().eq(self)
-    }
-}
-
-impl PartialEq<String> for Currency {
-    fn eq(&self, other: &String) -> bool {
-        self.as_str().eq(other)
-    }
-}
-
-impl PartialEq<Currency> for String {
-    fn eq(&self, other: &Currency) -> bool {
-        other.as_str().eq(self)
-    }
-}
-
-impl From<Currency> for String {
-    fn from(currency: Currency) -> Self {
-        currency.as_str().to_owned()
-    }
-}
-
-impl fmt::Display for Currency {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.as_str())
-    }
-}
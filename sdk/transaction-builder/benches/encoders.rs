@@ -0,0 +1,106 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Timings of the hot encoders, reported by criterion, along with the number of allocations each
+//! of them makes, counted by a wrapper around the system allocator.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use diem_transaction_builder::{
+    stdlib,
+    stdlib_script::{encode_stdlib_script, StdlibScript},
+};
+use diem_types::{
+    account_address::AccountAddress, account_config::gas_type_tag, transaction::TransactionArgument,
+};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Counts every allocation and reallocation before forwarding it to the system allocator.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Run `f` `iters` times and print the average number of allocations it made per run.
+fn report_allocations<R>(name: &str, iters: u64, mut f: impl FnMut() -> R) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..iters {
+        black_box(f());
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!(
+        "{}: {:.2} allocations/iter over {} iterations",
+        name,
+        allocations as f64 / iters as f64,
+        iters
+    );
+}
+
+fn peer_to_peer_args() -> Vec<TransactionArgument> {
+    vec![
+        TransactionArgument::Address(AccountAddress::ZERO),
+        TransactionArgument::U64(100),
+        TransactionArgument::U8Vector(vec![]),
+        TransactionArgument::U8Vector(vec![]),
+    ]
+}
+
+fn peer_to_peer(c: &mut Criterion) {
+    let payee = AccountAddress::random();
+    let encode = || {
+        stdlib::encode_peer_to_peer_with_metadata_script(gas_type_tag(), payee, 100, vec![], vec![])
+    };
+    c.bench_function("encode_peer_to_peer_with_metadata_script", |b| {
+        b.iter(encode)
+    });
+    report_allocations("encode_peer_to_peer_with_metadata_script", 100_000, encode);
+}
+
+fn stdlib_script(c: &mut Criterion) {
+    c.bench_function("encode_stdlib_script", |b| {
+        b.iter_batched(
+            peer_to_peer_args,
+            |args| {
+                encode_stdlib_script(
+                    StdlibScript::PeerToPeerWithMetadata,
+                    vec![gas_type_tag()],
+                    args,
+                )
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    // The arguments are built by the caller in both cases, so only the allocations of the
+    // encoder itself differ between the two reports.
+    report_allocations("peer_to_peer_args", 100_000, peer_to_peer_args);
+    report_allocations("encode_stdlib_script + peer_to_peer_args", 100_000, || {
+        encode_stdlib_script(
+            StdlibScript::PeerToPeerWithMetadata,
+            vec![gas_type_tag()],
+            peer_to_peer_args(),
+        )
+    });
+}
+
+criterion_group!(benches, peer_to_peer, stdlib_script);
+criterion_main!(benches);
@@ -0,0 +1,1081 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Builders with named setters for the scripts whose positional arguments are easy to mix up,
+//! for the shared key and validator key lifecycles, for the change sets applied by write set
+//! transactions and for the raw transactions that carry a script.
+
+use crate::{
+    currencies,
+    error::BuilderError,
+    fallible,
+    sliding_nonce::SlidingNonce,
+    stdlib::ScriptCall,
+    stdlib_script::{required_sender_role, SenderRole, StdlibScript},
+};
+use diem_types::{
+    access_path::{AccessPath, Path},
+    account_address::AccountAddress,
+    account_config::GAS_NAME,
+    chain_id::ChainId,
+    contract_event::ContractEvent,
+    transaction::{ChangeSet, RawTransaction, Script},
+    write_set::{WriteOp, WriteSetMut},
+};
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use std::{
+    collections::BTreeSet,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Builder for `peer_to_peer_with_metadata` scripts. The metadata and its signature are optional
+/// and default to empty vectors. Reserved recipients are only rejected when enabled with
+/// `reject_reserved_recipient`, see [`fallible::validate_recipient`].
+///
+/// ## Example
+/// ```
+/// use diem_transaction_builder::{builders::P2PTransferBuilder, currencies, stdlib};
+/// use diem_types::account_address::AccountAddress;
+///
+/// let gas = currencies::type_tag_for_code("GAS").unwrap();
+/// let payee = AccountAddress::from_hex_literal("0xdd").unwrap();
+/// let script = P2PTransferBuilder::new()
+///     .coin_type(gas.clone())
+///     .recipient(payee)
+///     .amount(1_000_000)
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     script,
+///     stdlib::encode_peer_to_peer_with_metadata_script(gas, payee, 1_000_000, vec![], vec![])
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct P2PTransferBuilder {
+    coin_type: Option<TypeTag>,
+    recipient: Option<AccountAddress>,
+    amount: Option<u64>,
+    metadata: Vec<u8>,
+    metadata_signature: Vec<u8>,
+    reject_reserved_recipient: bool,
+}
+
+impl P2PTransferBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn coin_type(mut self, coin_type: TypeTag) -> Self {
+        self.coin_type = Some(coin_type);
+        self
+    }
+
+    pub fn recipient(mut self, recipient: AccountAddress) -> Self {
+        self.recipient = Some(recipient);
+        self
+    }
+
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: Vec<u8>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn metadata_signature(mut self, metadata_signature: Vec<u8>) -> Self {
+        self.metadata_signature = metadata_signature;
+        self
+    }
+
+    pub fn reject_reserved_recipient(mut self, reject_reserved_recipient: bool) -> Self {
+        self.reject_reserved_recipient = reject_reserved_recipient;
+        self
+    }
+
+    pub fn build(self) -> Result<Script, BuilderError> {
+        let recipient = self
+            .recipient
+            .ok_or(BuilderError::MissingField { field: "recipient" })?;
+        if self.reject_reserved_recipient {
+            fallible::validate_recipient(recipient)?;
+        }
+        fallible::try_encode_peer_to_peer_with_metadata_script(
+            self.coin_type
+                .ok_or(BuilderError::MissingField { field: "coin_type" })?,
+            recipient,
+            self.amount
+                .ok_or(BuilderError::MissingField { field: "amount" })?,
+            self.metadata,
+            self.metadata_signature,
+        )
+    }
+}
+
+/// Builder for `create_parent_vasp_account` scripts. All fields but `add_all_currencies`, which
+/// defaults to `false`, must be set, and `human_name` must not be empty.
+#[derive(Clone, Debug, Default)]
+pub struct ParentVaspBuilder {
+    coin_type: Option<TypeTag>,
+    sliding_nonce: Option<SlidingNonce>,
+    new_account_address: Option<AccountAddress>,
+    auth_key_prefix: Option<Vec<u8>>,
+    human_name: Option<Vec<u8>>,
+    add_all_currencies: bool,
+}
+
+impl ParentVaspBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn coin_type(mut self, coin_type: TypeTag) -> Self {
+        self.coin_type = Some(coin_type);
+        self
+    }
+
+    pub fn sliding_nonce(mut self, sliding_nonce: SlidingNonce) -> Self {
+        self.sliding_nonce = Some(sliding_nonce);
+        self
+    }
+
+    pub fn new_account_address(mut self, new_account_address: AccountAddress) -> Self {
+        self.new_account_address = Some(new_account_address);
+        self
+    }
+
+    pub fn auth_key_prefix(mut self, auth_key_prefix: Vec<u8>) -> Self {
+        self.auth_key_prefix = Some(auth_key_prefix);
+        self
+    }
+
+    pub fn human_name(mut self, human_name: Vec<u8>) -> Self {
+        self.human_name = Some(human_name);
+        self
+    }
+
+    pub fn add_all_currencies(mut self, add_all_currencies: bool) -> Self {
+        self.add_all_currencies = add_all_currencies;
+        self
+    }
+
+    pub fn build(self) -> Result<Script, BuilderError> {
+        let human_name = self.human_name.ok_or(BuilderError::MissingField {
+            field: "human_name",
+        })?;
+        if human_name.is_empty() {
+            return Err(BuilderError::EmptyField {
+                field: "human_name",
+            });
+        }
+        fallible::try_encode_create_parent_vasp_account_script(
+            self.coin_type
+                .ok_or(BuilderError::MissingField { field: "coin_type" })?,
+            self.sliding_nonce.ok_or(BuilderError::MissingField {
+                field: "sliding_nonce",
+            })?,
+            self.new_account_address.ok_or(BuilderError::MissingField {
+                field: "new_account_address",
+            })?,
+            self.auth_key_prefix.ok_or(BuilderError::MissingField {
+                field: "auth_key_prefix",
+            })?,
+            human_name,
+            self.add_all_currencies,
+        )
+    }
+}
+
+/// Whether a child VASP account is created with coins moved from its parent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ChildVaspFunding {
+    Funded(u64),
+    Unfunded,
+}
+
+/// Builder for `create_child_vasp_account` scripts. Either `funded` or `unfunded` must be called
+/// to choose whether the parent moves coins of `coin_type` to the new account, and a funded
+/// account must receive a non-zero amount.
+///
+/// The script aborts unless the sender is a Parent VASP holding at least the initial balance,
+/// which can only be checked on-chain.
+///
+/// ## Example
+/// ```
+/// use diem_crypto::{ed25519::Ed25519PrivateKey, PrivateKey};
+/// use diem_transaction_builder::{
+///     builders::ChildVaspBuilder, currencies::Currency, fallible::auth_key_and_address, stdlib,
+/// };
+/// use std::convert::TryFrom;
+///
+/// let private_key = Ed25519PrivateKey::try_from(&[1u8; 32][..]).unwrap();
+/// let (auth_key_prefix, child_address) = auth_key_and_address(&private_key.public_key());
+/// let script = ChildVaspBuilder::new()
+///     .coin_type(Currency::XUS.into())
+///     .child_address(child_address)
+///     .auth_key_prefix(auth_key_prefix.clone())
+///     .funded(100)
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     script,
+///     stdlib::encode_create_child_vasp_account_script(
+///         Currency::XUS.into(),
+///         child_address,
+///         auth_key_prefix,
+///         false,
+///         100,
+///     )
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ChildVaspBuilder {
+    coin_type: Option<TypeTag>,
+    child_address: Option<AccountAddress>,
+    auth_key_prefix: Option<Vec<u8>>,
+    add_all_currencies: bool,
+    funding: Option<ChildVaspFunding>,
+}
+
+impl ChildVaspBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn coin_type(mut self, coin_type: TypeTag) -> Self {
+        self.coin_type = Some(coin_type);
+        self
+    }
+
+    pub fn child_address(mut self, child_address: AccountAddress) -> Self {
+        self.child_address = Some(child_address);
+        self
+    }
+
+    pub fn auth_key_prefix(mut self, auth_key_prefix: Vec<u8>) -> Self {
+        self.auth_key_prefix = Some(auth_key_prefix);
+        self
+    }
+
+    pub fn add_all_currencies(mut self, add_all_currencies: bool) -> Self {
+        self.add_all_currencies = add_all_currencies;
+        self
+    }
+
+    /// Move `initial_balance` coins of `coin_type` from the parent to the new account.
+    pub fn funded(mut self, initial_balance: u64) -> Self {
+        self.funding = Some(ChildVaspFunding::Funded(initial_balance));
+        self
+    }
+
+    /// Create the new account with a zero balance.
+    pub fn unfunded(mut self) -> Self {
+        self.funding = Some(ChildVaspFunding::Unfunded);
+        self
+    }
+
+    pub fn build(self) -> Result<Script, BuilderError> {
+        let child_initial_balance = match self.funding {
+            Some(ChildVaspFunding::Funded(0)) => return Err(BuilderError::ZeroInitialBalance),
+            Some(ChildVaspFunding::Funded(initial_balance)) => initial_balance,
+            Some(ChildVaspFunding::Unfunded) => 0,
+            None => return Err(BuilderError::MissingField { field: "funding" }),
+        };
+        fallible::try_encode_create_child_vasp_account_script(
+            self.coin_type
+                .ok_or(BuilderError::MissingField { field: "coin_type" })?,
+            self.child_address.ok_or(BuilderError::MissingField {
+                field: "child_address",
+            })?,
+            self.auth_key_prefix.ok_or(BuilderError::MissingField {
+                field: "auth_key_prefix",
+            })?,
+            self.add_all_currencies,
+            child_initial_balance,
+        )
+    }
+}
+
+/// Builder for the scripts that give an account a `SharedEd25519PublicKey`, whose key is also
+/// the authentication key of the account. The key is published with
+/// `publish_shared_ed25519_public_key`, or rotated with `rotate_shared_ed25519_public_key` if the
+/// account `already_published` one. Both scripts also rotate the authentication key, but
+/// `separate_rotation` prepends an explicit `rotate_authentication_key` for accounts that need it
+/// done in its own step.
+#[derive(Clone, Debug, Default)]
+pub struct SharedKeySetup {
+    public_key: Option<Vec<u8>>,
+    already_published: bool,
+    separate_rotation: bool,
+}
+
+impl SharedKeySetup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn public_key(mut self, public_key: Vec<u8>) -> Self {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    pub fn already_published(mut self, already_published: bool) -> Self {
+        self.already_published = already_published;
+        self
+    }
+
+    pub fn separate_rotation(mut self, separate_rotation: bool) -> Self {
+        self.separate_rotation = separate_rotation;
+        self
+    }
+
+    /// Return the scripts to send in order.
+    pub fn build(self) -> Result<Vec<Script>, BuilderError> {
+        let public_key = self.public_key.ok_or(BuilderError::MissingField {
+            field: "public_key",
+        })?;
+        let key = fallible::validate_shared_public_key(&public_key)?;
+        let mut scripts = vec![];
+        if self.separate_rotation {
+            scripts.push(fallible::encode_rotate_authentication_key_from_public_key(
+                &key,
+            ));
+        }
+        scripts.push(if self.already_published {
+            fallible::try_encode_rotate_shared_ed25519_public_key_script(public_key)?
+        } else {
+            fallible::try_encode_publish_shared_ed25519_public_key_script(public_key)?
+        });
+        Ok(scripts)
+    }
+}
+
+/// Builder for the script rotating the consensus key and network addresses of a validator
+/// together. The operator sends `set_validator_config_and_reconfigure`, which applies the new
+/// config at once, or `register_validator_config` if `reconfigure` is turned off, in which case
+/// it takes effect at the next reconfiguration. The network identity keys are carried by the
+/// encoded network addresses.
+#[derive(Clone, Debug)]
+pub struct ValidatorKeyRotation {
+    validator_account: Option<AccountAddress>,
+    consensus_pubkey: Option<Vec<u8>>,
+    validator_network_addresses: Option<Vec<u8>>,
+    fullnode_network_addresses: Option<Vec<u8>>,
+    reconfigure: bool,
+}
+
+impl ValidatorKeyRotation {
+    pub fn new() -> Self {
+        Self {
+            validator_account: None,
+            consensus_pubkey: None,
+            validator_network_addresses: None,
+            fullnode_network_addresses: None,
+            reconfigure: true,
+        }
+    }
+
+    pub fn validator_account(mut self, validator_account: AccountAddress) -> Self {
+        self.validator_account = Some(validator_account);
+        self
+    }
+
+    pub fn consensus_pubkey(mut self, consensus_pubkey: Vec<u8>) -> Self {
+        self.consensus_pubkey = Some(consensus_pubkey);
+        self
+    }
+
+    pub fn validator_network_addresses(mut self, validator_network_addresses: Vec<u8>) -> Self {
+        self.validator_network_addresses = Some(validator_network_addresses);
+        self
+    }
+
+    pub fn fullnode_network_addresses(mut self, fullnode_network_addresses: Vec<u8>) -> Self {
+        self.fullnode_network_addresses = Some(fullnode_network_addresses);
+        self
+    }
+
+    pub fn reconfigure(mut self, reconfigure: bool) -> Self {
+        self.reconfigure = reconfigure;
+        self
+    }
+
+    /// Role of the account that must send the script.
+    pub fn sender_role(&self) -> SenderRole {
+        let script = if self.reconfigure {
+            StdlibScript::SetValidatorConfigAndReconfigure
+        } else {
+            StdlibScript::RegisterValidatorConfig
+        };
+        required_sender_role(script)
+    }
+
+    pub fn build(self) -> Result<Script, BuilderError> {
+        let validator_account = self.validator_account.ok_or(BuilderError::MissingField {
+            field: "validator_account",
+        })?;
+        let consensus_pubkey = self.consensus_pubkey.ok_or(BuilderError::MissingField {
+            field: "consensus_pubkey",
+        })?;
+        let validator_network_addresses =
+            self.validator_network_addresses
+                .ok_or(BuilderError::MissingField {
+                    field: "validator_network_addresses",
+                })?;
+        let fullnode_network_addresses =
+            self.fullnode_network_addresses
+                .ok_or(BuilderError::MissingField {
+                    field: "fullnode_network_addresses",
+                })?;
+        if self.reconfigure {
+            fallible::try_encode_set_validator_config_and_reconfigure_script(
+                validator_account,
+                consensus_pubkey,
+                validator_network_addresses,
+                fullnode_network_addresses,
+            )
+        } else {
+            fallible::try_encode_register_validator_config_script(
+                validator_account,
+                consensus_pubkey,
+                validator_network_addresses,
+                fullnode_network_addresses,
+            )
+        }
+    }
+}
+
+impl Default for ValidatorKeyRotation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The scripts taking coins out of circulation: the account at `preburn_address`, usually a
+/// designated dealer, moves them to its preburn area with `preburn`, then the Treasury Compliance
+/// account destroys them with `burn` or returns them with `cancel_burn`. Building every step from
+/// one flow keeps their currency and preburn address in sync.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreburnBurnFlow {
+    currency: TypeTag,
+    preburn_address: AccountAddress,
+}
+
+impl PreburnBurnFlow {
+    pub fn new(currency: TypeTag, preburn_address: AccountAddress) -> Result<Self, BuilderError> {
+        currencies::validate_currency_type_tag(&currency)?;
+        Ok(Self {
+            currency,
+            preburn_address,
+        })
+    }
+
+    /// The `preburn` script, to be sent by the account at `preburn_address`.
+    pub fn preburn(&self, amount: u64) -> Result<Script, BuilderError> {
+        fallible::try_encode_preburn_script(self.currency.clone(), amount)
+    }
+
+    /// The `burn` script, to be sent by the Treasury Compliance account.
+    pub fn burn(&self, sliding_nonce: SlidingNonce) -> Result<Script, BuilderError> {
+        fallible::try_encode_burn_script(self.currency.clone(), sliding_nonce, self.preburn_address)
+    }
+
+    /// The `cancel_burn` script, to be sent by the Treasury Compliance account instead of `burn`.
+    pub fn cancel_burn(&self) -> Result<Script, BuilderError> {
+        fallible::try_encode_cancel_burn_script(self.currency.clone(), self.preburn_address)
+    }
+
+    /// The `preburn` and `burn` scripts, to be sent in order.
+    pub fn preburn_and_burn(
+        &self,
+        amount: u64,
+        sliding_nonce: SlidingNonce,
+    ) -> Result<(Script, Script), BuilderError> {
+        Ok((self.preburn(amount)?, self.burn(sliding_nonce)?))
+    }
+}
+
+/// Accumulates raw writes and events into a `ChangeSet`, e.g. for genesis tooling or admin write
+/// sets that go beyond a stdlib upgrade.
+#[derive(Clone, Debug, Default)]
+pub struct ChangeSetBuilder {
+    write_set: Vec<(AccessPath, WriteOp)>,
+    events: Vec<ContractEvent>,
+}
+
+impl ChangeSetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_write(mut self, access_path: AccessPath, op: WriteOp) -> Self {
+        self.write_set.push((access_path, op));
+        self
+    }
+
+    pub fn push_event(mut self, event: ContractEvent) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Fails if an access path was written more than once, see [`write_set_from_pairs`].
+    pub fn build(self) -> Result<ChangeSet, BuilderError> {
+        encode_changeset_with_events(write_set_from_pairs(self.write_set)?, self.events)
+    }
+}
+
+/// Collect `pairs` into a `WriteSetMut`, rejecting an access path written more than once since
+/// only one of the writes would take effect.
+pub fn write_set_from_pairs(
+    pairs: Vec<(AccessPath, WriteOp)>,
+) -> Result<WriteSetMut, BuilderError> {
+    let mut seen = BTreeSet::new();
+    for (access_path, _) in &pairs {
+        if !seen.insert(access_path) {
+            return Err(BuilderError::DuplicateAccessPath {
+                path: access_path.clone(),
+            });
+        }
+    }
+    Ok(WriteSetMut::new(pairs))
+}
+
+/// Freeze `write_set` into a `ChangeSet` emitting `events`, e.g. for admin write sets that should
+/// be observable by event subscribers.
+pub fn encode_changeset_with_events(
+    write_set: WriteSetMut,
+    events: Vec<ContractEvent>,
+) -> Result<ChangeSet, BuilderError> {
+    let write_set = write_set
+        .freeze()
+        .map_err(|e| BuilderError::WriteSet(e.to_string()))?;
+    Ok(ChangeSet::new(write_set, events))
+}
+
+/// Return the modules written by `change_set`, in write set order, e.g. to check a serialized
+/// stdlib upgrade against the modules it is expected to publish. Writes to resources and paths
+/// that cannot be decoded are skipped.
+pub fn changeset_module_ids(change_set: &ChangeSet) -> Vec<ModuleId> {
+    change_set
+        .write_set()
+        .iter()
+        .filter_map(
+            |(access_path, _)| match bcs::from_bytes(&access_path.path) {
+                Ok(Path::Code(module_id)) => Some(module_id),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+/// Maximum amount of gas a `RawTransactionBuilder` allows when none is set.
+pub const DEFAULT_MAX_GAS_AMOUNT: u64 = 1_000_000;
+
+/// How long after being built a transaction stays valid when no expiration time is set.
+pub const DEFAULT_EXPIRATION_OFFSET_SECS: u64 = 100;
+
+/// Builder for a `RawTransaction` running a script. Gas is paid in `GAS` at a zero unit price
+/// unless set otherwise, and the transaction expires `DEFAULT_EXPIRATION_OFFSET_SECS` after it is
+/// built.
+#[derive(Clone, Debug)]
+pub struct RawTransactionBuilder {
+    script: Script,
+    sender: Option<AccountAddress>,
+    sequence_number: Option<u64>,
+    chain_id: Option<ChainId>,
+    max_gas_amount: u64,
+    gas_unit_price: u64,
+    gas_currency_code: String,
+    expiration_timestamp_secs: Option<u64>,
+}
+
+impl RawTransactionBuilder {
+    pub fn new(script: Script) -> Self {
+        Self {
+            script,
+            sender: None,
+            sequence_number: None,
+            chain_id: None,
+            max_gas_amount: DEFAULT_MAX_GAS_AMOUNT,
+            gas_unit_price: 0,
+            gas_currency_code: GAS_NAME.to_owned(),
+            expiration_timestamp_secs: None,
+        }
+    }
+
+    pub fn sender(mut self, sender: AccountAddress) -> Self {
+        self.sender = Some(sender);
+        self
+    }
+
+    pub fn sequence_number(mut self, sequence_number: u64) -> Self {
+        self.sequence_number = Some(sequence_number);
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn max_gas_amount(mut self, max_gas_amount: u64) -> Self {
+        self.max_gas_amount = max_gas_amount;
+        self
+    }
+
+    pub fn gas_unit_price(mut self, gas_unit_price: u64) -> Self {
+        self.gas_unit_price = gas_unit_price;
+        self
+    }
+
+    pub fn gas_currency_code<T: Into<String>>(mut self, gas_currency_code: T) -> Self {
+        self.gas_currency_code = gas_currency_code.into();
+        self
+    }
+
+    pub fn expiration_timestamp_secs(mut self, expiration_timestamp_secs: u64) -> Self {
+        self.expiration_timestamp_secs = Some(expiration_timestamp_secs);
+        self
+    }
+
+    pub fn build(self) -> Result<RawTransaction, BuilderError> {
+        let expiration_timestamp_secs = match self.expiration_timestamp_secs {
+            Some(expiration_timestamp_secs) => expiration_timestamp_secs,
+            None => {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("System time is before the UNIX epoch")
+                    .as_secs()
+                    + DEFAULT_EXPIRATION_OFFSET_SECS
+            }
+        };
+        Ok(RawTransaction::new_script(
+            self.sender
+                .ok_or(BuilderError::MissingField { field: "sender" })?,
+            self.sequence_number.ok_or(BuilderError::MissingField {
+                field: "sequence_number",
+            })?,
+            self.script,
+            self.max_gas_amount,
+            self.gas_unit_price,
+            self.gas_currency_code,
+            expiration_timestamp_secs,
+            self.chain_id
+                .ok_or(BuilderError::MissingField { field: "chain_id" })?,
+        ))
+    }
+}
+
+/// Starts a `RawTransaction` running `call`, which still needs a sender, a sequence number and a
+/// chain id before it can be built and signed.
+impl From<ScriptCall> for RawTransactionBuilder {
+    fn from(call: ScriptCall) -> Self {
+        Self::new(call.encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdlib;
+    use diem_crypto::{ed25519::Ed25519PrivateKey, PrivateKey};
+    use diem_types::{
+        account_config::gas_type_tag, event::EventKey,
+        transaction::authenticator::AuthenticationKey,
+    };
+    use move_core_types::{identifier::Identifier, language_storage::CORE_CODE_ADDRESS};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_p2p_transfer_builder() {
+        let recipient = AccountAddress::random();
+        let script = P2PTransferBuilder::new()
+            .coin_type(TypeTag::Bool)
+            .recipient(recipient)
+            .amount(42)
+            .metadata_signature(vec![2])
+            .build()
+            .unwrap();
+        assert_eq!(
+            script,
+            stdlib::encode_peer_to_peer_with_metadata_script(
+                TypeTag::Bool,
+                recipient,
+                42,
+                vec![],
+                vec![2],
+            )
+        );
+
+        assert_eq!(
+            P2PTransferBuilder::new()
+                .coin_type(TypeTag::Bool)
+                .amount(42)
+                .build(),
+            Err(BuilderError::MissingField { field: "recipient" })
+        );
+    }
+
+    #[test]
+    fn test_p2p_transfer_builder_reserved_recipient() {
+        let builder = P2PTransferBuilder::new().coin_type(TypeTag::Bool).amount(1);
+        for recipient in &[AccountAddress::ZERO, CORE_CODE_ADDRESS] {
+            assert!(builder.clone().recipient(*recipient).build().is_ok());
+            assert_eq!(
+                builder
+                    .clone()
+                    .recipient(*recipient)
+                    .reject_reserved_recipient(true)
+                    .build(),
+                Err(BuilderError::ReservedRecipient {
+                    recipient: *recipient
+                })
+            );
+        }
+        assert!(builder
+            .recipient(AccountAddress::random())
+            .reject_reserved_recipient(true)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_parent_vasp_builder() {
+        let address = AccountAddress::random();
+        let prefix = vec![0; fallible::AUTH_KEY_PREFIX_LENGTH];
+        let builder = ParentVaspBuilder::new()
+            .coin_type(TypeTag::Bool)
+            .sliding_nonce(SlidingNonce::new(1).unwrap())
+            .new_account_address(address)
+            .auth_key_prefix(prefix.clone());
+        assert_eq!(
+            builder.clone().human_name(b"vasp".to_vec()).build(),
+            Ok(stdlib::encode_create_parent_vasp_account_script(
+                TypeTag::Bool,
+                1,
+                address,
+                prefix,
+                b"vasp".to_vec(),
+                false,
+            ))
+        );
+        assert_eq!(
+            builder.clone().human_name(vec![]).build(),
+            Err(BuilderError::EmptyField {
+                field: "human_name"
+            })
+        );
+        assert_eq!(
+            builder.clone().build(),
+            Err(BuilderError::MissingField {
+                field: "human_name"
+            })
+        );
+        assert_eq!(
+            builder
+                .auth_key_prefix(vec![0])
+                .human_name(b"vasp".to_vec())
+                .build(),
+            Err(BuilderError::BadAuthKeyPrefixLength {
+                got: 1,
+                expected: fallible::AUTH_KEY_PREFIX_LENGTH
+            })
+        );
+    }
+
+    #[test]
+    fn test_child_vasp_builder() {
+        let address = AccountAddress::random();
+        let prefix = vec![0; fallible::AUTH_KEY_PREFIX_LENGTH];
+        let builder = ChildVaspBuilder::new()
+            .coin_type(TypeTag::Bool)
+            .child_address(address)
+            .auth_key_prefix(prefix.clone());
+        assert_eq!(
+            builder.clone().funded(10).build(),
+            Ok(stdlib::encode_create_child_vasp_account_script(
+                TypeTag::Bool,
+                address,
+                prefix.clone(),
+                false,
+                10,
+            ))
+        );
+        assert_eq!(
+            builder.clone().unfunded().build(),
+            Ok(stdlib::encode_create_child_vasp_account_script(
+                TypeTag::Bool,
+                address,
+                prefix,
+                false,
+                0,
+            ))
+        );
+        assert_eq!(
+            builder.clone().funded(0).build(),
+            Err(BuilderError::ZeroInitialBalance)
+        );
+        assert_eq!(
+            builder.build(),
+            Err(BuilderError::MissingField { field: "funding" })
+        );
+    }
+
+    #[test]
+    fn test_shared_key_setup() {
+        let public_key = Ed25519PrivateKey::try_from(&[1u8; 32][..])
+            .unwrap()
+            .public_key();
+        let bytes = public_key.to_bytes().to_vec();
+        let setup = SharedKeySetup::new().public_key(bytes.clone());
+        assert_eq!(
+            setup.clone().build(),
+            Ok(vec![
+                stdlib::encode_publish_shared_ed25519_public_key_script(bytes.clone())
+            ])
+        );
+        assert_eq!(
+            setup.clone().already_published(true).build(),
+            Ok(vec![
+                stdlib::encode_rotate_shared_ed25519_public_key_script(bytes.clone())
+            ])
+        );
+        assert_eq!(
+            setup.separate_rotation(true).build(),
+            Ok(vec![
+                stdlib::encode_rotate_authentication_key_script(
+                    AuthenticationKey::ed25519(&public_key).to_vec()
+                ),
+                stdlib::encode_publish_shared_ed25519_public_key_script(bytes),
+            ])
+        );
+        assert_eq!(
+            SharedKeySetup::new().public_key(vec![1; 31]).build(),
+            Err(BuilderError::BadKeyLength {
+                field: "public_key",
+                got: 31,
+                expected: 32,
+            })
+        );
+        assert_eq!(
+            SharedKeySetup::new().build(),
+            Err(BuilderError::MissingField {
+                field: "public_key"
+            })
+        );
+    }
+
+    #[test]
+    fn test_validator_key_rotation() {
+        let validator = AccountAddress::random();
+        let rotation = ValidatorKeyRotation::new()
+            .validator_account(validator)
+            .consensus_pubkey(vec![1; 32])
+            .validator_network_addresses(vec![2])
+            .fullnode_network_addresses(vec![3]);
+        assert_eq!(rotation.sender_role(), SenderRole::ValidatorOperator);
+        assert_eq!(
+            rotation.clone().build(),
+            Ok(stdlib::encode_set_validator_config_and_reconfigure_script(
+                validator,
+                vec![1; 32],
+                vec![2],
+                vec![3],
+            ))
+        );
+        assert_eq!(
+            rotation.clone().reconfigure(false).build(),
+            Ok(stdlib::encode_register_validator_config_script(
+                validator,
+                vec![1; 32],
+                vec![2],
+                vec![3],
+            ))
+        );
+        assert_eq!(
+            rotation.clone().consensus_pubkey(vec![1; 31]).build(),
+            Err(BuilderError::BadKeyLength {
+                field: "consensus_pubkey",
+                got: 31,
+                expected: 32,
+            })
+        );
+        assert_eq!(
+            ValidatorKeyRotation::new().build(),
+            Err(BuilderError::MissingField {
+                field: "validator_account"
+            })
+        );
+    }
+
+    #[test]
+    fn test_preburn_burn_flow() {
+        let preburn_address = AccountAddress::random();
+        let flow = PreburnBurnFlow::new(gas_type_tag(), preburn_address).unwrap();
+        assert_eq!(
+            flow.preburn_and_burn(10, SlidingNonce::new(1).unwrap()),
+            Ok((
+                stdlib::encode_preburn_script(gas_type_tag(), 10),
+                stdlib::encode_burn_script(gas_type_tag(), 1, preburn_address),
+            ))
+        );
+        assert_eq!(
+            flow.cancel_burn(),
+            Ok(stdlib::encode_cancel_burn_script(
+                gas_type_tag(),
+                preburn_address
+            ))
+        );
+        assert!(PreburnBurnFlow::new(TypeTag::Bool, preburn_address).is_err());
+    }
+
+    #[test]
+    fn test_change_set_builder() {
+        let address = AccountAddress::random();
+        let first = AccessPath::new(address, vec![0]);
+        let second = AccessPath::new(address, vec![1]);
+        let (write_set, events) = ChangeSetBuilder::new()
+            .push_write(first.clone(), WriteOp::Value(vec![1]))
+            .push_write(second.clone(), WriteOp::Deletion)
+            .build()
+            .unwrap()
+            .into_inner();
+        assert_eq!(
+            write_set.iter().cloned().collect::<Vec<_>>(),
+            vec![
+                (first.clone(), WriteOp::Value(vec![1])),
+                (second, WriteOp::Deletion),
+            ]
+        );
+        assert!(events.is_empty());
+
+        assert_eq!(
+            ChangeSetBuilder::new()
+                .push_write(first.clone(), WriteOp::Value(vec![1]))
+                .push_write(first.clone(), WriteOp::Deletion)
+                .build()
+                .unwrap_err(),
+            BuilderError::DuplicateAccessPath { path: first }
+        );
+    }
+
+    #[test]
+    fn test_write_set_from_pairs() {
+        let address = AccountAddress::random();
+        let first = AccessPath::new(address, vec![0]);
+        let second = AccessPath::new(address, vec![1]);
+        let write_set = write_set_from_pairs(vec![
+            (first.clone(), WriteOp::Value(vec![1])),
+            (second.clone(), WriteOp::Deletion),
+        ])
+        .unwrap();
+        assert_eq!(write_set.freeze().unwrap().iter().count(), 2);
+        assert_eq!(
+            write_set_from_pairs(vec![
+                (first.clone(), WriteOp::Value(vec![1])),
+                (second, WriteOp::Deletion),
+                (first.clone(), WriteOp::Deletion),
+            ])
+            .unwrap_err(),
+            BuilderError::DuplicateAccessPath { path: first }
+        );
+    }
+
+    #[test]
+    fn test_encode_changeset_with_events() {
+        let event = ContractEvent::new(
+            EventKey::new_from_address(&AccountAddress::random(), 0),
+            0,
+            TypeTag::Bool,
+            vec![1],
+        );
+        let change_set = ChangeSetBuilder::new()
+            .push_event(event.clone())
+            .build()
+            .unwrap();
+        assert_eq!(
+            change_set,
+            encode_changeset_with_events(WriteSetMut::new(vec![]), vec![event.clone()]).unwrap()
+        );
+        assert_eq!(change_set.events(), &[event]);
+    }
+
+    #[test]
+    fn test_changeset_module_ids() {
+        let module_id = ModuleId::new(AccountAddress::random(), Identifier::new("Module").unwrap());
+        let builder = ChangeSetBuilder::new()
+            .push_write(
+                AccessPath::code_access_path(module_id.clone()),
+                WriteOp::Value(vec![1]),
+            )
+            .push_write(
+                AccessPath::new(AccountAddress::random(), vec![0xff]),
+                WriteOp::Deletion,
+            );
+        assert_eq!(
+            changeset_module_ids(&builder.build().unwrap()),
+            vec![module_id]
+        );
+    }
+
+    #[test]
+    fn test_raw_transaction_builder() {
+        let script = stdlib::encode_burn_txn_fees_script(TypeTag::Bool);
+        let sender = AccountAddress::random();
+        let txn = RawTransactionBuilder::new(script.clone())
+            .sender(sender)
+            .sequence_number(7)
+            .chain_id(ChainId::test())
+            .expiration_timestamp_secs(100)
+            .build()
+            .unwrap();
+        assert_eq!(
+            txn,
+            RawTransaction::new_script(
+                sender,
+                7,
+                script.clone(),
+                DEFAULT_MAX_GAS_AMOUNT,
+                0,
+                GAS_NAME.to_owned(),
+                100,
+                ChainId::test(),
+            )
+        );
+
+        assert_eq!(
+            RawTransactionBuilder::new(script)
+                .sender(sender)
+                .chain_id(ChainId::test())
+                .build(),
+            Err(BuilderError::MissingField {
+                field: "sequence_number"
+            })
+        );
+    }
+
+    #[test]
+    fn test_raw_transaction_builder_from_call() {
+        let call = ScriptCall::BurnTxnFees {
+            coin_type: TypeTag::Bool,
+        };
+        let sender = AccountAddress::random();
+        let build = |builder: RawTransactionBuilder| {
+            builder
+                .sender(sender)
+                .sequence_number(0)
+                .chain_id(ChainId::test())
+                .expiration_timestamp_secs(100)
+                .build()
+                .unwrap()
+        };
+        assert_eq!(
+            build(RawTransactionBuilder::from(call)),
+            build(RawTransactionBuilder::new(
+                stdlib::encode_burn_txn_fees_script(TypeTag::Bool)
+            ))
+        );
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Counterparts of the generated encoders that take their currency `TypeTag` by reference.
+//!
+//! The encoders in [`crate::stdlib`] consume their type argument, so building many scripts in the
+//! same currency means cloning the tag at every call site. The `*_ref` functions below borrow it
+//! instead and clone it once, when it is stored in the script.
+
+use crate::stdlib;
+use diem_types::{account_address::AccountAddress, transaction::Script};
+use move_core_types::language_storage::TypeTag;
+
+/// Defines a `*_ref` function for each listed encoder, taking the leading `TypeTag` arguments,
+/// listed in brackets, by reference and passing the other arguments through unchanged.
+macro_rules! ref_encoders {
+    ($(
+        $(#[$attr:meta])*
+        fn $ref_name:ident => $name:ident([$($tag:ident),+] $(, $arg:ident: $ty:ty)* $(,)?);
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub fn $ref_name($($tag: &TypeTag),+ $(, $arg: $ty)*) -> Script {
+                stdlib::$name($($tag.clone()),+ $(, $arg)*)
+            }
+        )*
+    };
+}
+
+ref_encoders! {
+    /// Version of [`stdlib::encode_add_currency_to_account_script`] borrowing `currency`.
+    fn encode_add_currency_to_account_script_ref => encode_add_currency_to_account_script(
+        [currency],
+    );
+
+    /// Version of [`stdlib::encode_burn_script`] borrowing `token`.
+    fn encode_burn_script_ref => encode_burn_script(
+        [token],
+        sliding_nonce: u64,
+        preburn_address: AccountAddress,
+    );
+
+    /// Version of [`stdlib::encode_burn_txn_fees_script`] borrowing `coin_type`.
+    fn encode_burn_txn_fees_script_ref => encode_burn_txn_fees_script([coin_type]);
+
+    /// Version of [`stdlib::encode_cancel_burn_script`] borrowing `token`.
+    fn encode_cancel_burn_script_ref => encode_cancel_burn_script(
+        [token],
+        preburn_address: AccountAddress,
+    );
+
+    /// Version of [`stdlib::encode_create_child_vasp_account_script`] borrowing `coin_type`.
+    fn encode_create_child_vasp_account_script_ref => encode_create_child_vasp_account_script(
+        [coin_type],
+        child_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        add_all_currencies: bool,
+        child_initial_balance: u64,
+    );
+
+    /// Version of [`stdlib::encode_create_designated_dealer_script`] borrowing `currency`.
+    fn encode_create_designated_dealer_script_ref => encode_create_designated_dealer_script(
+        [currency],
+        sliding_nonce: u64,
+        addr: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        human_name: Vec<u8>,
+        add_all_currencies: bool,
+    );
+
+    /// Version of [`stdlib::encode_create_parent_vasp_account_script`] borrowing `coin_type`.
+    fn encode_create_parent_vasp_account_script_ref => encode_create_parent_vasp_account_script(
+        [coin_type],
+        sliding_nonce: u64,
+        new_account_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        human_name: Vec<u8>,
+        add_all_currencies: bool,
+    );
+
+    /// Version of [`stdlib::encode_peer_to_peer_with_metadata_script`] borrowing `currency`.
+    fn encode_peer_to_peer_with_metadata_script_ref => encode_peer_to_peer_with_metadata_script(
+        [currency],
+        payee: AccountAddress,
+        amount: u64,
+        metadata: Vec<u8>,
+        metadata_signature: Vec<u8>,
+    );
+
+    /// Version of [`stdlib::encode_preburn_script`] borrowing `token`.
+    fn encode_preburn_script_ref => encode_preburn_script([token], amount: u64);
+
+    /// Version of [`stdlib::encode_tiered_mint_script`] borrowing `coin_type`.
+    fn encode_tiered_mint_script_ref => encode_tiered_mint_script(
+        [coin_type],
+        sliding_nonce: u64,
+        designated_dealer_address: AccountAddress,
+        mint_amount: u64,
+        tier_index: u64,
+    );
+
+    /// Version of [`stdlib::encode_update_exchange_rate_script`] borrowing `currency`.
+    fn encode_update_exchange_rate_script_ref => encode_update_exchange_rate_script(
+        [currency],
+        sliding_nonce: u64,
+        new_exchange_rate_numerator: u64,
+        new_exchange_rate_denominator: u64,
+    );
+
+    /// Version of [`stdlib::encode_update_minting_ability_script`] borrowing `currency`.
+    fn encode_update_minting_ability_script_ref => encode_update_minting_ability_script(
+        [currency],
+        allow_minting: bool,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ref_encoders() {
+        let currency = TypeTag::Bool;
+        let payee = AccountAddress::random();
+        for amount in 1..4 {
+            assert_eq!(
+                encode_peer_to_peer_with_metadata_script_ref(
+                    &currency,
+                    payee,
+                    amount,
+                    vec![],
+                    vec![]
+                ),
+                stdlib::encode_peer_to_peer_with_metadata_script(
+                    currency.clone(),
+                    payee,
+                    amount,
+                    vec![],
+                    vec![]
+                )
+            );
+        }
+        assert_eq!(
+            encode_burn_txn_fees_script_ref(&currency),
+            stdlib::encode_burn_txn_fees_script(currency)
+        );
+    }
+
+    mod multi_type {
+        use diem_types::transaction::Script;
+        use move_core_types::language_storage::TypeTag;
+
+        // No script takes more than one type argument yet, so a local encoder stands in for one.
+        mod stdlib {
+            use diem_types::transaction::{Script, TransactionArgument};
+            use move_core_types::language_storage::TypeTag;
+
+            pub fn encode_swap_script(from: TypeTag, to: TypeTag, amount: u64) -> Script {
+                Script::new(
+                    vec![],
+                    vec![from, to],
+                    vec![TransactionArgument::U64(amount)],
+                )
+            }
+        }
+
+        ref_encoders! {
+            fn encode_swap_script_ref => encode_swap_script([from, to], amount: u64);
+        }
+
+        #[test]
+        fn test_multiple_type_args() {
+            let (from, to) = (TypeTag::Bool, TypeTag::U8);
+            assert_eq!(
+                encode_swap_script_ref(&from, &to, 10),
+                stdlib::encode_swap_script(from, to, 10)
+            );
+        }
+    }
+}
@@ -0,0 +1,342 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The well-known currencies and helpers to build scripts from them.
+
+use crate::{error::BuilderError, stdlib};
+use diem_types::{
+    account_address::AccountAddress,
+    account_config::{
+        currency_code_from_type_tag, from_currency_code_string, gas_type_tag,
+        type_tag_for_currency_code, xus_tag, GAS_NAME, XUS_NAME,
+    },
+    transaction::Script,
+};
+use move_core_types::language_storage::{StructTag, TypeTag};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Code of the gas currency.
+pub const GAS: &str = GAS_NAME;
+
+/// Code of the XUS currency.
+pub const XUS: &str = XUS_NAME;
+
+/// Codes of all of the well-known currencies.
+pub const WELL_KNOWN_CURRENCY_CODES: &[&str] = &[GAS, XUS];
+
+/// Return the `TypeTag` of the currency with code `code`, i.e. `0x1::<code>::<code>`, or `None`
+/// if `code` is not a valid currency code.
+pub fn type_tag_for_code(code: &str) -> Option<TypeTag> {
+    from_currency_code_string(code)
+        .ok()
+        .map(type_tag_for_currency_code)
+}
+
+/// Check that `type_tag` has the shape of a currency, i.e. `0x1::<code>::<code>` without type
+/// parameters. Whether the currency is actually registered on-chain is not checked.
+pub fn validate_currency_type_tag(type_tag: &TypeTag) -> Result<(), BuilderError> {
+    currency_code_from_type_tag(type_tag.clone())
+        .map(|_| ())
+        .map_err(|_| BuilderError::BadCurrencyCode {
+            code: type_tag.to_string(),
+        })
+}
+
+/// A currency to move or hold coins of: one of the well-known currencies, or any other currency
+/// given by the `StructTag` of its coin, e.g. as read from on-chain config.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    XDX,
+    XUS,
+    Custom(StructTag),
+}
+
+impl Currency {
+    /// Code of the currency. For a `Custom` currency this is the name of its coin struct, which
+    /// is also the name of its module.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Currency::XDX => GAS_NAME, //////// 0L ////////
+            Currency::XUS => XUS_NAME,
+            Currency::Custom(struct_tag) => struct_tag.name.as_str(),
+        }
+    }
+
+    pub fn type_tag(&self) -> TypeTag {
+        match self {
+            Currency::XDX => gas_type_tag(), //////// 0L ////////
+            Currency::XUS => xus_tag(),
+            Currency::Custom(struct_tag) => TypeTag::Struct(struct_tag.clone()),
+        }
+    }
+}
+
+impl From<StructTag> for Currency {
+    fn from(struct_tag: StructTag) -> Self {
+        Currency::Custom(struct_tag)
+    }
+}
+
+impl PartialEq<str> for Currency {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(other)
+    }
+}
+
+impl PartialEq<Currency> for str {
+    fn eq(&self, other: &Currency) -> bool {
+        other.as_str().eq(self)
+    }
+}
+
+impl PartialEq<String> for Currency {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str().eq(other)
+    }
+}
+
+impl PartialEq<Currency> for String {
+    fn eq(&self, other: &Currency) -> bool {
+        other.as_str().eq(self)
+    }
+}
+
+impl From<Currency> for String {
+    fn from(currency: Currency) -> Self {
+        currency.as_str().to_owned()
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Currency> for TypeTag {
+    fn from(currency: Currency) -> Self {
+        match currency {
+            Currency::Custom(struct_tag) => TypeTag::Struct(struct_tag),
+            currency => currency.type_tag(),
+        }
+    }
+}
+
+// The helpers below accept anything that converts into a `TypeTag`: a `Currency`, including a
+// `Currency::Custom` built from a `StructTag`, or a `TypeTag` directly.
+
+pub fn encode_add_currency_to_account_for<C: Into<TypeTag>>(currency: C) -> Script {
+    stdlib::encode_add_currency_to_account_script(currency.into())
+}
+
+/// Build one `add_currency_to_account` script per currency in `currencies`, e.g. to enable several
+/// currencies on an existing account. See [`crate::fallible::try_encode_add_currencies`] for a
+/// variant that rejects empty and duplicate lists.
+pub fn encode_add_currencies(currencies: &[TypeTag]) -> Vec<Script> {
+    currencies
+        .iter()
+        .cloned()
+        .map(encode_add_currency_to_account_for)
+        .collect()
+}
+
+pub fn encode_burn_txn_fees_for<C: Into<TypeTag>>(currency: C) -> Script {
+    stdlib::encode_burn_txn_fees_script(currency.into())
+}
+
+pub fn encode_preburn_for<C: Into<TypeTag>>(currency: C, amount: u64) -> Script {
+    stdlib::encode_preburn_script(currency.into(), amount)
+}
+
+/// Build a `peer_to_peer_with_metadata` script without metadata.
+///
+/// ## Example
+/// ```
+/// use diem_transaction_builder::{
+///     currencies::{encode_peer_to_peer_for, Currency},
+///     stdlib,
+/// };
+/// use diem_types::account_address::AccountAddress;
+///
+/// let payee = AccountAddress::from_hex_literal("0xdd").unwrap();
+/// assert_eq!(
+///     encode_peer_to_peer_for(Currency::XUS, payee, 10),
+///     stdlib::encode_peer_to_peer_with_metadata_script(
+///         Currency::XUS.into(),
+///         payee,
+///         10,
+///         vec![],
+///         vec![],
+///     )
+/// );
+/// ```
+pub fn encode_peer_to_peer_for<C: Into<TypeTag>>(
+    currency: C,
+    payee: AccountAddress,
+    amount: u64,
+) -> Script {
+    stdlib::encode_peer_to_peer_with_metadata_script(currency.into(), payee, amount, vec![], vec![])
+}
+
+/// Build one `peer_to_peer_with_metadata` script per `(payee, amount)` pair, e.g. for payroll
+/// style disbursements where each transfer is submitted as its own transaction. See
+/// [`crate::fallible::try_encode_batch_transfer`] for a variant that rejects empty batches and
+/// zero amounts.
+pub fn encode_batch_transfer<C: Into<TypeTag>>(
+    currency: C,
+    transfers: &[(AccountAddress, u64)],
+) -> Vec<Script> {
+    let currency = currency.into();
+    transfers
+        .iter()
+        .map(|(payee, amount)| encode_peer_to_peer_for(currency.clone(), *payee, *amount))
+        .collect()
+}
+
+/// Build a `peer_to_peer_with_metadata` script transferring `amount` coins of
+/// `transfer_currency`, along with the code of `gas_currency` to set as the
+/// `gas_currency_code` of the raw transaction carrying it.
+///
+/// The two currencies are independent: the transfer is paid from the sender's balance in
+/// `transfer_currency` while gas is charged to its balance in `gas_currency`, so the sender must
+/// hold both. Keeping them explicit avoids silently paying gas in the transfer currency.
+pub fn encode_transfer_paying_gas_in<T: Into<TypeTag>, G: Into<TypeTag>>(
+    transfer_currency: T,
+    gas_currency: G,
+    recipient: AccountAddress,
+    amount: u64,
+) -> Result<(Script, String), BuilderError> {
+    let gas_currency = gas_currency.into();
+    let gas_currency_code = currency_code_from_type_tag(gas_currency.clone()).map_err(|_| {
+        BuilderError::BadCurrencyCode {
+            code: gas_currency.to_string(),
+        }
+    })?;
+    Ok((
+        encode_peer_to_peer_for(transfer_currency, recipient, amount),
+        gas_currency_code.into_string(),
+    ))
+}
+
+/// Build a `peer_to_peer_with_metadata` script from a currency code and a hex encoded payee
+/// address, optionally `0x` prefixed. The address must have all of its `AccountAddress::LENGTH`
+/// bytes.
+pub fn encode_transfer_from_str(
+    currency_code: &str,
+    payee: &str,
+    amount: u64,
+) -> Result<Script, BuilderError> {
+    let currency =
+        type_tag_for_code(currency_code).ok_or_else(|| BuilderError::BadCurrencyCode {
+            code: currency_code.to_string(),
+        })?;
+    let payee_address = AccountAddress::from_hex(payee.strip_prefix("0x").unwrap_or(payee))
+        .map_err(|_| BuilderError::BadAddress {
+            address: payee.to_string(),
+        })?;
+    Ok(encode_peer_to_peer_for(currency, payee_address, amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_tag_for_code() {
+        assert_eq!(type_tag_for_code(GAS), Some(gas_type_tag()));
+        assert_eq!(type_tag_for_code(XUS), Some(xus_tag()));
+        for code in WELL_KNOWN_CURRENCY_CODES {
+            assert!(type_tag_for_code(code).is_some());
+        }
+        assert_eq!(type_tag_for_code("not a code"), None);
+    }
+
+    #[test]
+    fn test_validate_currency_type_tag() {
+        assert_eq!(validate_currency_type_tag(&gas_type_tag()), Ok(()));
+        assert_eq!(
+            validate_currency_type_tag(&Currency::XUS.type_tag()),
+            Ok(())
+        );
+        assert_eq!(
+            validate_currency_type_tag(&TypeTag::U64),
+            Err(BuilderError::BadCurrencyCode {
+                code: TypeTag::U64.to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_encode_transfer_from_str() {
+        let payee = AccountAddress::random();
+        assert_eq!(
+            encode_transfer_from_str(GAS, &format!("0x{}", payee.to_hex()), 5),
+            Ok(encode_peer_to_peer_for(gas_type_tag(), payee, 5))
+        );
+        assert_eq!(
+            encode_transfer_from_str(GAS, &payee.to_hex(), 5),
+            Ok(encode_peer_to_peer_for(gas_type_tag(), payee, 5))
+        );
+        assert_eq!(
+            encode_transfer_from_str(GAS, "0x1", 5),
+            Err(BuilderError::BadAddress {
+                address: "0x1".to_string()
+            })
+        );
+        assert_eq!(
+            encode_transfer_from_str("", &payee.to_hex(), 5),
+            Err(BuilderError::BadCurrencyCode {
+                code: "".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_encode_transfer_paying_gas_in() {
+        let recipient = AccountAddress::random();
+        assert_eq!(
+            encode_transfer_paying_gas_in(Currency::XUS, Currency::XDX, recipient, 5),
+            Ok((
+                encode_peer_to_peer_for(xus_tag(), recipient, 5),
+                GAS.to_string()
+            ))
+        );
+        assert_eq!(
+            encode_transfer_paying_gas_in(Currency::XUS, TypeTag::Bool, recipient, 5),
+            Err(BuilderError::BadCurrencyCode {
+                code: TypeTag::Bool.to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_currency_type_tags() {
+        assert_eq!(TypeTag::from(Currency::XUS), xus_tag());
+        assert_eq!(TypeTag::from(Currency::XDX), gas_type_tag());
+        assert_eq!(
+            encode_preburn_for(Currency::XUS, 10),
+            stdlib::encode_preburn_script(xus_tag(), 10)
+        );
+        let gas_struct_tag = match gas_type_tag() {
+            TypeTag::Struct(struct_tag) => struct_tag,
+            _ => unreachable!(),
+        };
+        let custom = Currency::from(gas_struct_tag);
+        assert_eq!(custom.as_str(), GAS);
+        assert_eq!(custom.type_tag(), gas_type_tag());
+        assert_eq!(
+            encode_add_currency_to_account_for(custom.clone()),
+            stdlib::encode_add_currency_to_account_script(gas_type_tag())
+        );
+        assert_eq!(
+            encode_preburn_for(custom, 10),
+            stdlib::encode_preburn_script(gas_type_tag(), 10)
+        );
+        assert_eq!(
+            encode_preburn_for(TypeTag::Bool, 10),
+            stdlib::encode_preburn_script(TypeTag::Bool, 10)
+        );
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The message a payee's compliance key signs to approve a transfer that requires dual
+//! attestation (travel rule), and which transfers require it.
+
+use diem_types::account_address::AccountAddress;
+
+/// Appended to every dual attestation message, see `DualAttestation::DOMAIN_SEPARATOR`. Signers
+/// that build the message themselves must use these exact bytes.
+pub const DUAL_ATTESTATION_DOMAIN_SEPARATOR: &[u8] = b"@@$$DIEM_ATTEST$$@@";
+
+/// Dual attestation limit set at genesis, in whole `GAS` coins, see
+/// `DualAttestation::INITIAL_DUAL_ATTESTATION_LIMIT`. The Treasury Compliance account can change
+/// it with `update_dual_attestation_limit`.
+pub const INITIAL_DUAL_ATTESTATION_LIMIT: u64 = 1000;
+
+/// `INITIAL_DUAL_ATTESTATION_LIMIT` in micro-`GAS`, the unit of the amounts of scripts, as `GAS`
+/// has a scaling factor of 10^6.
+pub const INITIAL_MICRO_DUAL_ATTESTATION_LIMIT: u64 = INITIAL_DUAL_ATTESTATION_LIMIT * 1_000_000;
+
+/// Return whether a `peer_to_peer_with_metadata` transfer of `amount` needs a `metadata_signature`
+/// under the initial limit, mirroring `DualAttestation::dual_attestation_required`.
+///
+/// `same_vasp` must also be `true` when the payer or the payee is not a VASP, since only transfers
+/// between two distinct VASPs are subject to dual attestation. Amounts in another currency than
+/// `GAS` are converted on-chain with its exchange rate, which is not known here, so such a transfer
+/// between distinct VASPs is always assumed to need a signature.
+pub fn requires_dual_attestation(amount: u64, same_vasp: bool, currency_is_gas: bool) -> bool {
+    if same_vasp {
+        return false;
+    }
+    !currency_is_gas || amount >= INITIAL_MICRO_DUAL_ATTESTATION_LIMIT
+}
+
+/// Return the bytes that the `metadata_signature` argument of `peer_to_peer_with_metadata` must
+/// sign: `metadata | bcs(payer) | bcs(amount) | DUAL_ATTESTATION_DOMAIN_SEPARATOR`.
+///
+/// Note that the address is the one of the *payer*, i.e. the sender of the transfer, as this is
+/// what `DualAttestation::assert_payment_ok` verifies against.
+pub fn dual_attestation_message(payer: AccountAddress, metadata: &[u8], amount: u64) -> Vec<u8> {
+    let mut message = metadata.to_vec();
+    bcs::serialize_into(&mut message, &payer).expect("AccountAddress is serializable");
+    bcs::serialize_into(&mut message, &amount).expect("u64 is serializable");
+    message.extend(DUAL_ATTESTATION_DOMAIN_SEPARATOR);
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_dual_attestation() {
+        let limit = INITIAL_MICRO_DUAL_ATTESTATION_LIMIT;
+        assert!(requires_dual_attestation(limit, false, true));
+        assert!(!requires_dual_attestation(limit - 1, false, true));
+        assert!(!requires_dual_attestation(limit, true, true));
+        assert!(requires_dual_attestation(1, false, false));
+        assert!(!requires_dual_attestation(limit, true, false));
+    }
+
+    #[test]
+    fn test_domain_separator() {
+        assert_eq!(DUAL_ATTESTATION_DOMAIN_SEPARATOR, b"@@$$DIEM_ATTEST$$@@");
+        assert_eq!(DUAL_ATTESTATION_DOMAIN_SEPARATOR.len(), 19);
+    }
+
+    #[test]
+    fn test_dual_attestation_message() {
+        let payer = AccountAddress::from_hex_literal("0xc5ab123458df0003415689adbb47326d").unwrap();
+        let message = dual_attestation_message(payer, b"metadata", 1_000_000);
+        let expected = [
+            &b"metadata"[..],
+            &hex::decode("c5ab123458df0003415689adbb47326d").unwrap(),
+            &[0x40, 0x42, 0x0f, 0, 0, 0, 0, 0],
+            b"@@$$DIEM_ATTEST$$@@",
+        ]
+        .concat();
+        assert_eq!(message, expected);
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use diem_crypto::HashValue;
+use diem_types::{access_path::AccessPath, account_address::AccountAddress};
+use move_core_types::language_storage::TypeTag;
+use thiserror::Error;
+
+/// Errors raised when the arguments of a script are rejected before it is encoded.
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum BuilderError {
+    #[error("Amount `{}` is {}, which does not fit in a u64", field, amount)]
+    AmountOverflow { field: &'static str, amount: u128 },
+    #[error("Argument `{}` has length {}, the maximum is {}", field, len, max)]
+    ArgTooLong {
+        field: &'static str,
+        len: usize,
+        max: usize,
+    },
+    #[error("Invalid account address `{}`", address)]
+    BadAddress { address: String },
+    #[error("Authentication key prefix has length {}, expected {}", got, expected)]
+    BadAuthKeyPrefixLength { got: usize, expected: usize },
+    #[error("Invalid currency code `{}`", code)]
+    BadCurrencyCode { code: String },
+    #[error("Key argument `{}` has length {}, expected {}", field, got, expected)]
+    BadKeyLength {
+        field: &'static str,
+        got: usize,
+        expected: usize,
+    },
+    #[error("Script does not survive a BCS round trip: {}", _0)]
+    BcsRoundTrip(String),
+    #[error("Access path {} is written more than once", path)]
+    DuplicateAccessPath { path: AccessPath },
+    #[error("Currency {} is listed more than once", currency)]
+    DuplicateCurrency { currency: TypeTag },
+    #[error("Sliding nonce {} is used by more than one script", nonce)]
+    DuplicateSlidingNonce { nonce: u64 },
+    #[error("A batch must contain at least one item")]
+    EmptyBatch,
+    #[error("Argument `{}` must not be empty", field)]
+    EmptyField { field: &'static str },
+    #[error("Key argument `{}` must not be empty", field)]
+    EmptyKey { field: &'static str },
+    #[error("Invalid argument `{}`: {}", input, reason)]
+    InvalidArgument { input: String, reason: String },
+    #[error("Invalid Diem version {}", major)]
+    InvalidDiemVersion { major: u64 },
+    #[error("Invalid URL `{}`: {}", url, reason)]
+    InvalidUrl { url: String, reason: String },
+    #[error("Script {} has no `{}` argument", script, field)]
+    MissingArgument { script: String, field: &'static str },
+    #[error("Required field `{}` was not set", field)]
+    MissingField { field: &'static str },
+    #[error("Recipient {} is a reserved address", recipient)]
+    ReservedRecipient { recipient: AccountAddress },
+    #[error("Script `{}` is not in the stdlib source", name)]
+    ScriptNotInSource { name: &'static str },
+    #[error("Sliding nonce {} is outside of {}..={}", nonce, min, max)]
+    SlidingNonceOutOfRange { nonce: u64, min: u64, max: u64 },
+    #[error("Stdlib hashes to {}, expected {}", got, expected)]
+    StdlibHashMismatch { expected: HashValue, got: HashValue },
+    #[error("Tier index {} is out of range, the maximum is {}", tier_index, max)]
+    TierIndexOutOfRange { tier_index: u64, max: u64 },
+    #[error("Script has {} arguments, the maximum is {}", count, max)]
+    TooManyArgs { count: usize, max: usize },
+    #[error("Unknown script `{}`, expected one of: {}", name, expected)]
+    UnknownScript { name: String, expected: String },
+    #[error("Failed to build write set: {}", _0)]
+    WriteSet(String),
+    #[error("Script {} takes {} {}, got {}", script, expected, kind, got)]
+    WrongArgumentCount {
+        script: &'static str,
+        kind: &'static str,
+        got: usize,
+        expected: usize,
+    },
+    #[error(
+        "Argument `{}` of script {} has type {}, expected {}",
+        name,
+        script,
+        got,
+        expected
+    )]
+    WrongArgumentType {
+        script: &'static str,
+        name: &'static str,
+        got: &'static str,
+        expected: &'static str,
+    },
+    #[error("Transfer {} in the batch has an amount of zero", index)]
+    ZeroAmount { index: usize },
+    #[error("Argument `{}` must not be zero", field)]
+    ZeroArgument { field: &'static str },
+    #[error("Exchange rate denominator must not be zero")]
+    ZeroDenominator,
+    #[error("A funded child VASP account needs a non-zero initial balance")]
+    ZeroInitialBalance,
+    #[error("A sliding nonce of zero disables replay protection")]
+    ZeroSlidingNonce,
+}
@@ -0,0 +1,1374 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fallible counterparts of the generated script encoders.
+//!
+//! The encoders in [`crate::stdlib`] accept any argument values and leave all validation to the
+//! Move VM. The `try_encode_*` functions below check the arguments that are usually built from
+//! user input, so that a malformed value is reported as a [`BuilderError`] rather than as a
+//! panic or an on-chain abort.
+
+use crate::{
+    currencies,
+    error::BuilderError,
+    sliding_nonce::SlidingNonce,
+    stdlib,
+    stdlib_script::{self, argument_schema, expect_args, StdlibScript},
+};
+use diem_crypto::ed25519::{Ed25519PublicKey, ED25519_PUBLIC_KEY_LENGTH, ED25519_SIGNATURE_LENGTH};
+use diem_types::{
+    account_address::AccountAddress,
+    transaction::{authenticator::AuthenticationKey, Script, TransactionArgument},
+};
+use move_core_types::{
+    gas_schedule::MAX_TRANSACTION_SIZE_IN_BYTES,
+    language_storage::{TypeTag, CORE_CODE_ADDRESS},
+};
+use std::{collections::BTreeSet, convert::TryFrom};
+use url::Url;
+
+/// Length of the `auth_key_prefix` argument taken by the account creation scripts. The prefix is
+/// concatenated with the new account address to form its authentication key.
+pub const AUTH_KEY_PREFIX_LENGTH: usize = AuthenticationKey::LENGTH - AccountAddress::LENGTH;
+
+/// Check that `auth_key_prefix` has exactly `AUTH_KEY_PREFIX_LENGTH` bytes.
+pub fn validate_auth_key_prefix(auth_key_prefix: &[u8]) -> Result<(), BuilderError> {
+    if auth_key_prefix.len() != AUTH_KEY_PREFIX_LENGTH {
+        return Err(BuilderError::BadAuthKeyPrefixLength {
+            got: auth_key_prefix.len(),
+            expected: AUTH_KEY_PREFIX_LENGTH,
+        });
+    }
+    Ok(())
+}
+
+/// Return the `auth_key_prefix` argument of the account creation scripts for `key`, i.e. the
+/// `AUTH_KEY_PREFIX_LENGTH` bytes of the key that are not part of the account address.
+pub fn auth_key_prefix(key: &AuthenticationKey) -> Vec<u8> {
+    key.prefix().to_vec()
+}
+
+/// Derive the authentication key of `public_key` and split it into the `auth_key_prefix`
+/// argument expected by the account creation scripts and the address of the new account.
+pub fn auth_key_and_address(public_key: &Ed25519PublicKey) -> (Vec<u8>, AccountAddress) {
+    let auth_key = AuthenticationKey::ed25519(public_key);
+    (auth_key_prefix(&auth_key), auth_key.derived_address())
+}
+
+/// Build a `rotate_authentication_key` script setting the sender's authentication key to the one
+/// derived from `public_key`. The script takes the hashed key, not the public key itself, see
+/// [`stdlib::encode_rotate_authentication_key_script`] to pass an already derived key.
+///
+/// ## Example
+/// ```
+/// use diem_crypto::{ed25519::Ed25519PrivateKey, PrivateKey};
+/// use diem_transaction_builder::{
+///     fallible::encode_rotate_authentication_key_from_public_key, stdlib,
+/// };
+/// use diem_types::transaction::authenticator::AuthenticationKey;
+/// use std::convert::TryFrom;
+///
+/// let public_key = Ed25519PrivateKey::try_from(&[1u8; 32][..])
+///     .unwrap()
+///     .public_key();
+/// assert_eq!(
+///     encode_rotate_authentication_key_from_public_key(&public_key),
+///     stdlib::encode_rotate_authentication_key_script(
+///         AuthenticationKey::ed25519(&public_key).to_vec()
+///     )
+/// );
+/// ```
+pub fn encode_rotate_authentication_key_from_public_key(public_key: &Ed25519PublicKey) -> Script {
+    stdlib::encode_rotate_authentication_key_script(AuthenticationKey::ed25519(public_key).to_vec())
+}
+
+/// Check that the key passed as argument `field` is not empty.
+pub fn validate_non_empty_key(field: &'static str, key: &[u8]) -> Result<(), BuilderError> {
+    if key.is_empty() {
+        return Err(BuilderError::EmptyKey { field });
+    }
+    Ok(())
+}
+
+/// Check that the key passed as argument `field` has exactly `expected` bytes.
+pub fn validate_key_length(
+    field: &'static str,
+    key: &[u8],
+    expected: usize,
+) -> Result<(), BuilderError> {
+    if key.len() != expected {
+        return Err(BuilderError::BadKeyLength {
+            field,
+            got: key.len(),
+            expected,
+        });
+    }
+    Ok(())
+}
+
+/// Default maximum length of the byte vector arguments checked by the `try_encode_*` functions,
+/// other than those bounded by [`ArgumentLimits`]. A transaction carrying a longer argument
+/// exceeds `MAX_TRANSACTION_SIZE_IN_BYTES` and would be rejected by the validators anyway.
+pub const MAX_BYTES_ARGUMENT_LENGTH: usize = MAX_TRANSACTION_SIZE_IN_BYTES as usize;
+
+/// Check that the byte vector passed as argument `field` has at most `max` bytes, e.g. one of the
+/// [`ArgumentLimits`] on metadata, URLs and keys.
+pub fn validate_max_length(
+    field: &'static str,
+    bytes: &[u8],
+    max: usize,
+) -> Result<(), BuilderError> {
+    if bytes.len() > max {
+        return Err(BuilderError::ArgTooLong {
+            field,
+            len: bytes.len(),
+            max,
+        });
+    }
+    Ok(())
+}
+
+/// Check that the byte vector passed as argument `field` has at most `MAX_BYTES_ARGUMENT_LENGTH`
+/// bytes.
+pub fn validate_argument_length(field: &'static str, bytes: &[u8]) -> Result<(), BuilderError> {
+    validate_max_length(field, bytes, MAX_BYTES_ARGUMENT_LENGTH)
+}
+
+/// Default maximum length of the `metadata` of a peer to peer transfer. The chain does not bound
+/// it, so this only catches a blob attached by mistake.
+pub const MAX_METADATA_LENGTH: usize = 1024;
+
+/// Default maximum length of the `metadata_signature` of a peer to peer transfer, which is either
+/// empty or the Ed25519 signature checked by the `DualAttestation` module.
+pub const MAX_METADATA_SIGNATURE_LENGTH: usize = ED25519_SIGNATURE_LENGTH;
+
+/// Default maximum length of the base URL set with `rotate_dual_attestation_info`.
+pub const MAX_URL_LENGTH: usize = 2048;
+
+/// Default maximum length of the `new_key` of the key rotation scripts. Both authentication keys
+/// and the Ed25519 compliance keys of dual attestation are rejected on-chain unless 32 bytes long.
+pub const MAX_KEY_LENGTH: usize = AuthenticationKey::LENGTH;
+
+/// Maximum lengths of the metadata, URL and key arguments, checked by the `try_encode_*`
+/// functions taking them. The defaults are the `MAX_*_LENGTH` constants above, and the
+/// `*_with_limits` variants of these functions take other limits.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ArgumentLimits {
+    pub metadata: usize,
+    pub metadata_signature: usize,
+    pub url: usize,
+    pub key: usize,
+}
+
+impl Default for ArgumentLimits {
+    fn default() -> Self {
+        Self {
+            metadata: MAX_METADATA_LENGTH,
+            metadata_signature: MAX_METADATA_SIGNATURE_LENGTH,
+            url: MAX_URL_LENGTH,
+            key: MAX_KEY_LENGTH,
+        }
+    }
+}
+
+/// Check that `public_key` is a valid Ed25519 public key, as required of the key shared by the
+/// holders of a `SharedEd25519PublicKey` resource, and return it.
+pub fn validate_shared_public_key(public_key: &[u8]) -> Result<Ed25519PublicKey, BuilderError> {
+    validate_key_length("public_key", public_key, ED25519_PUBLIC_KEY_LENGTH)?;
+    Ed25519PublicKey::try_from(public_key).map_err(|e| BuilderError::InvalidArgument {
+        input: hex::encode(public_key),
+        reason: e.to_string(),
+    })
+}
+
+/// Check that `sliding_nonce` is not 0. The `SlidingNonce` module accepts 0 but skips recording
+/// it, so a script sent with it can be replayed, see [`crate::sliding_nonce`].
+pub fn validate_sliding_nonce(sliding_nonce: u64) -> Result<(), BuilderError> {
+    if sliding_nonce == 0 {
+        return Err(BuilderError::ZeroSlidingNonce);
+    }
+    Ok(())
+}
+
+/// Check that the amount `field` is not 0, e.g. for mints and burns that would otherwise only
+/// emit events.
+pub fn validate_non_zero(field: &'static str, value: u64) -> Result<(), BuilderError> {
+    if value == 0 {
+        return Err(BuilderError::ZeroArgument { field });
+    }
+    Ok(())
+}
+
+/// Maximum number of arguments of a script. Its parameters are locals of its `main` function,
+/// which the bytecode indexes with a `u8`, see `LOCAL_INDEX_MAX`.
+pub const MAX_SCRIPT_ARGS: usize = 255;
+
+/// Check that `script` has at most `max` arguments, e.g. `MAX_SCRIPT_ARGS`, as a guard after
+/// [`crate::stdlib_script::encode_stdlib_script`], which accepts any arguments.
+pub fn validate_arg_count(script: &Script, max: usize) -> Result<(), BuilderError> {
+    let count = stdlib_script::arg_count(script);
+    if count > max {
+        return Err(BuilderError::TooManyArgs { count, max });
+    }
+    Ok(())
+}
+
+/// Check that `recipient` is neither the zero address nor the address of the core modules, which
+/// no one controls, so that coins sent to them are lost.
+pub fn validate_recipient(recipient: AccountAddress) -> Result<(), BuilderError> {
+    if recipient == AccountAddress::ZERO || recipient == CORE_CODE_ADDRESS {
+        return Err(BuilderError::ReservedRecipient { recipient });
+    }
+    Ok(())
+}
+
+/// Check that the denominator of an exchange rate is not 0, which would make the rate undefined
+/// and abort the script on-chain.
+pub fn validate_exchange_rate_denominator(denominator: u64) -> Result<(), BuilderError> {
+    if denominator == 0 {
+        return Err(BuilderError::ZeroDenominator);
+    }
+    Ok(())
+}
+
+/// Check that `url` is an absolute `http` or `https` URL, e.g. the base URL of a VASP.
+pub fn validate_url(url: &str) -> Result<(), BuilderError> {
+    let invalid = |reason: String| BuilderError::InvalidUrl {
+        url: url.to_string(),
+        reason,
+    };
+    if url.is_empty() {
+        return Err(invalid("empty URL".to_string()));
+    }
+    let parsed = Url::parse(url).map_err(|e| invalid(e.to_string()))?;
+    match parsed.scheme() {
+        "http" | "https" => Ok(()),
+        scheme => Err(invalid(format!("unsupported scheme `{}`", scheme))),
+    }
+}
+
+/// Number of mint tiers every Designated Dealer has for each currency.
+pub const DESIGNATED_DEALER_MINT_TIERS: u64 = 4;
+
+/// Check that `tier_index` refers to one of the `DESIGNATED_DEALER_MINT_TIERS` mint tiers.
+///
+/// The tiers are enforced on-chain by the `DesignatedDealer` module, which remains the source of
+/// truth. This only catches an out of range index before the transaction is submitted.
+pub fn validate_tier_index(tier_index: u64) -> Result<(), BuilderError> {
+    if tier_index >= DESIGNATED_DEALER_MINT_TIERS {
+        return Err(BuilderError::TierIndexOutOfRange {
+            tier_index,
+            max: DESIGNATED_DEALER_MINT_TIERS - 1,
+        });
+    }
+    Ok(())
+}
+
+/// Check that `major` is a Diem version that can be set on-chain. Versions start at 1, so 0 is
+/// rejected as it is the default value of an unset field rather than a real version.
+pub fn validate_diem_version(major: u64) -> Result<(), BuilderError> {
+    if major == 0 {
+        return Err(BuilderError::InvalidDiemVersion { major });
+    }
+    Ok(())
+}
+
+/// Defines a `try_*` function for each listed encoder. The checks are evaluated in order and the
+/// arguments are only handed to the generated encoder once all of them pass.
+macro_rules! try_encoders {
+    ($(
+        $(#[$attr:meta])*
+        fn $try_name:ident => $name:ident($($arg:ident: $ty:ty),* $(,)?) { $($check:expr;)* }
+    )*) => {
+        $(
+            $(#[$attr])*
+            // Only the `SlidingNonce` arguments need converting, the others are passed as is.
+            #[allow(clippy::useless_conversion)]
+            pub fn $try_name($($arg: $ty),*) -> Result<Script, BuilderError> {
+                $($check?;)*
+                Ok(stdlib::$name($($arg.into()),*))
+            }
+        )*
+    };
+}
+
+/// Like `try_encoders!`, but also defines a `*_with_limits` function checking the arguments
+/// against the [`ArgumentLimits`] it is given, which the `try_*` function calls with the defaults.
+macro_rules! try_encoders_with_limits {
+    ($(
+        $(#[$attr:meta])*
+        fn $try_name:ident;
+        $(#[$limits_attr:meta])*
+        fn $limits_name:ident => $name:ident($($arg:ident: $ty:ty),* $(,)?) |$limits:ident| {
+            $($check:expr;)*
+        }
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub fn $try_name($($arg: $ty),*) -> Result<Script, BuilderError> {
+                $limits_name($($arg,)* &ArgumentLimits::default())
+            }
+
+            $(#[$limits_attr])*
+            #[allow(clippy::useless_conversion)]
+            pub fn $limits_name(
+                $($arg: $ty,)*
+                $limits: &ArgumentLimits,
+            ) -> Result<Script, BuilderError> {
+                $($check?;)*
+                Ok(stdlib::$name($($arg.into()),*))
+            }
+        )*
+    };
+}
+
+try_encoders! {
+    /// Fallible version of [`stdlib::encode_add_currency_to_account_script`].
+    fn try_encode_add_currency_to_account_script => encode_add_currency_to_account_script(
+        currency: TypeTag,
+    ) {
+        currencies::validate_currency_type_tag(&currency);
+    }
+
+    /// Fallible version of [`stdlib::encode_add_recovery_rotation_capability_script`].
+    fn try_encode_add_recovery_rotation_capability_script
+        => encode_add_recovery_rotation_capability_script(
+        recovery_address: AccountAddress,
+    ) {}
+
+    /// Fallible version of [`stdlib::encode_add_validator_and_reconfigure_script`].
+    fn try_encode_add_validator_and_reconfigure_script
+        => encode_add_validator_and_reconfigure_script(
+        sliding_nonce: SlidingNonce,
+        validator_name: Vec<u8>,
+        validator_address: AccountAddress,
+    ) {
+        validate_argument_length("validator_name", &validator_name);
+    }
+
+    /// Fallible version of [`stdlib::encode_burn_script`].
+    fn try_encode_burn_script => encode_burn_script(
+        token: TypeTag,
+        sliding_nonce: SlidingNonce,
+        preburn_address: AccountAddress,
+    ) {}
+
+    /// Fallible version of [`stdlib::encode_burn_txn_fees_script`].
+    fn try_encode_burn_txn_fees_script => encode_burn_txn_fees_script(coin_type: TypeTag) {}
+
+    /// Fallible version of [`stdlib::encode_cancel_burn_script`].
+    fn try_encode_cancel_burn_script => encode_cancel_burn_script(
+        token: TypeTag,
+        preburn_address: AccountAddress,
+    ) {}
+
+    /// Fallible version of [`stdlib::encode_create_child_vasp_account_script`].
+    fn try_encode_create_child_vasp_account_script => encode_create_child_vasp_account_script(
+        coin_type: TypeTag,
+        child_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        add_all_currencies: bool,
+        child_initial_balance: u64,
+    ) {
+        validate_auth_key_prefix(&auth_key_prefix);
+    }
+
+    /// Fallible version of [`stdlib::encode_create_designated_dealer_script`].
+    fn try_encode_create_designated_dealer_script => encode_create_designated_dealer_script(
+        currency: TypeTag,
+        sliding_nonce: SlidingNonce,
+        addr: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        human_name: Vec<u8>,
+        add_all_currencies: bool,
+    ) {
+        validate_auth_key_prefix(&auth_key_prefix);
+        validate_argument_length("human_name", &human_name);
+    }
+
+    /// Fallible version of [`stdlib::encode_create_parent_vasp_account_script`].
+    fn try_encode_create_parent_vasp_account_script => encode_create_parent_vasp_account_script(
+        coin_type: TypeTag,
+        sliding_nonce: SlidingNonce,
+        new_account_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        human_name: Vec<u8>,
+        add_all_currencies: bool,
+    ) {
+        validate_auth_key_prefix(&auth_key_prefix);
+        validate_argument_length("human_name", &human_name);
+    }
+
+    /// Fallible version of [`stdlib::encode_create_recovery_address_script`].
+    fn try_encode_create_recovery_address_script => encode_create_recovery_address_script() {}
+
+    /// Fallible version of [`stdlib::encode_create_validator_account_script`].
+    fn try_encode_create_validator_account_script => encode_create_validator_account_script(
+        sliding_nonce: SlidingNonce,
+        new_account_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        human_name: Vec<u8>,
+    ) {
+        validate_auth_key_prefix(&auth_key_prefix);
+        validate_argument_length("human_name", &human_name);
+    }
+
+    /// Fallible version of [`stdlib::encode_create_validator_operator_account_script`].
+    fn try_encode_create_validator_operator_account_script
+        => encode_create_validator_operator_account_script(
+        sliding_nonce: SlidingNonce,
+        new_account_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        human_name: Vec<u8>,
+    ) {
+        validate_auth_key_prefix(&auth_key_prefix);
+        validate_argument_length("human_name", &human_name);
+    }
+
+    /// Fallible version of [`stdlib::encode_freeze_account_script`].
+    fn try_encode_freeze_account_script => encode_freeze_account_script(
+        sliding_nonce: SlidingNonce,
+        to_freeze_account: AccountAddress,
+    ) {}
+
+    /// Fallible version of [`stdlib::encode_preburn_script`].
+    fn try_encode_preburn_script => encode_preburn_script(token: TypeTag, amount: u64) {
+        validate_non_zero("amount", amount);
+    }
+
+    /// Fallible version of [`stdlib::encode_publish_shared_ed25519_public_key_script`].
+    fn try_encode_publish_shared_ed25519_public_key_script
+        => encode_publish_shared_ed25519_public_key_script(
+        public_key: Vec<u8>,
+    ) {
+        validate_key_length("public_key", &public_key, ED25519_PUBLIC_KEY_LENGTH);
+    }
+
+    /// Fallible version of [`stdlib::encode_register_validator_config_script`].
+    fn try_encode_register_validator_config_script => encode_register_validator_config_script(
+        validator_account: AccountAddress,
+        consensus_pubkey: Vec<u8>,
+        validator_network_addresses: Vec<u8>,
+        fullnode_network_addresses: Vec<u8>,
+    ) {
+        validate_non_empty_key("consensus_pubkey", &consensus_pubkey);
+        validate_key_length("consensus_pubkey", &consensus_pubkey, ED25519_PUBLIC_KEY_LENGTH);
+        validate_argument_length("validator_network_addresses", &validator_network_addresses);
+        validate_argument_length("fullnode_network_addresses", &fullnode_network_addresses);
+    }
+
+    /// Fallible version of [`stdlib::encode_remove_validator_and_reconfigure_script`].
+    fn try_encode_remove_validator_and_reconfigure_script
+        => encode_remove_validator_and_reconfigure_script(
+        sliding_nonce: SlidingNonce,
+        validator_name: Vec<u8>,
+        validator_address: AccountAddress,
+    ) {
+        validate_argument_length("validator_name", &validator_name);
+    }
+
+    /// Fallible version of [`stdlib::encode_rotate_shared_ed25519_public_key_script`].
+    fn try_encode_rotate_shared_ed25519_public_key_script
+        => encode_rotate_shared_ed25519_public_key_script(
+        public_key: Vec<u8>,
+    ) {
+        validate_key_length("public_key", &public_key, ED25519_PUBLIC_KEY_LENGTH);
+    }
+
+    /// Fallible version of [`stdlib::encode_set_validator_config_and_reconfigure_script`].
+    fn try_encode_set_validator_config_and_reconfigure_script
+        => encode_set_validator_config_and_reconfigure_script(
+        validator_account: AccountAddress,
+        consensus_pubkey: Vec<u8>,
+        validator_network_addresses: Vec<u8>,
+        fullnode_network_addresses: Vec<u8>,
+    ) {
+        validate_non_empty_key("consensus_pubkey", &consensus_pubkey);
+        validate_key_length("consensus_pubkey", &consensus_pubkey, ED25519_PUBLIC_KEY_LENGTH);
+        validate_argument_length("validator_network_addresses", &validator_network_addresses);
+        validate_argument_length("fullnode_network_addresses", &fullnode_network_addresses);
+    }
+
+    /// Fallible version of [`stdlib::encode_set_validator_operator_script`].
+    fn try_encode_set_validator_operator_script => encode_set_validator_operator_script(
+        operator_name: Vec<u8>,
+        operator_account: AccountAddress,
+    ) {
+        validate_argument_length("operator_name", &operator_name);
+    }
+
+    /// Fallible version of [`stdlib::encode_set_validator_operator_with_nonce_admin_script`].
+    fn try_encode_set_validator_operator_with_nonce_admin_script
+        => encode_set_validator_operator_with_nonce_admin_script(
+        sliding_nonce: SlidingNonce,
+        operator_name: Vec<u8>,
+        operator_account: AccountAddress,
+    ) {
+        validate_argument_length("operator_name", &operator_name);
+    }
+
+    /// Fallible version of [`stdlib::encode_tiered_mint_script`].
+    fn try_encode_tiered_mint_script => encode_tiered_mint_script(
+        coin_type: TypeTag,
+        sliding_nonce: SlidingNonce,
+        designated_dealer_address: AccountAddress,
+        mint_amount: u64,
+        tier_index: u64,
+    ) {
+        validate_non_zero("mint_amount", mint_amount);
+        validate_tier_index(tier_index);
+    }
+
+    /// Fallible version of [`stdlib::encode_unfreeze_account_script`].
+    fn try_encode_unfreeze_account_script => encode_unfreeze_account_script(
+        sliding_nonce: SlidingNonce,
+        to_unfreeze_account: AccountAddress,
+    ) {}
+
+    /// Fallible version of [`stdlib::encode_update_diem_version_script`].
+    fn try_encode_update_diem_version_script => encode_update_diem_version_script(
+        sliding_nonce: SlidingNonce,
+        major: u64,
+    ) {
+        validate_diem_version(major);
+    }
+
+    /// Fallible version of [`stdlib::encode_update_dual_attestation_limit_script`].
+    fn try_encode_update_dual_attestation_limit_script
+        => encode_update_dual_attestation_limit_script(
+        sliding_nonce: SlidingNonce,
+        new_micro_xdx_limit: u64,
+    ) {}
+
+    /// Fallible version of [`stdlib::encode_update_exchange_rate_script`].
+    fn try_encode_update_exchange_rate_script => encode_update_exchange_rate_script(
+        currency: TypeTag,
+        sliding_nonce: SlidingNonce,
+        new_exchange_rate_numerator: u64,
+        new_exchange_rate_denominator: u64,
+    ) {
+        validate_exchange_rate_denominator(new_exchange_rate_denominator);
+    }
+
+    /// Fallible version of [`stdlib::encode_update_minting_ability_script`].
+    fn try_encode_update_minting_ability_script => encode_update_minting_ability_script(
+        currency: TypeTag,
+        allow_minting: bool,
+    ) {}
+}
+
+try_encoders_with_limits! {
+    /// Fallible version of [`stdlib::encode_peer_to_peer_with_metadata_script`].
+    fn try_encode_peer_to_peer_with_metadata_script;
+    /// Version of [`try_encode_peer_to_peer_with_metadata_script`] checking `metadata` and
+    /// `metadata_signature` against `limits`.
+    fn try_encode_peer_to_peer_with_metadata_script_with_limits
+        => encode_peer_to_peer_with_metadata_script(
+        currency: TypeTag,
+        payee: AccountAddress,
+        amount: u64,
+        metadata: Vec<u8>,
+        metadata_signature: Vec<u8>,
+    ) |limits| {
+        validate_max_length("metadata", &metadata, limits.metadata);
+        validate_max_length("metadata_signature", &metadata_signature, limits.metadata_signature);
+    }
+
+    /// Fallible version of [`stdlib::encode_rotate_authentication_key_script`].
+    fn try_encode_rotate_authentication_key_script;
+    /// Version of [`try_encode_rotate_authentication_key_script`] checking `new_key` against
+    /// `limits`.
+    fn try_encode_rotate_authentication_key_script_with_limits
+        => encode_rotate_authentication_key_script(
+        new_key: Vec<u8>,
+    ) |limits| {
+        validate_non_empty_key("new_key", &new_key);
+        validate_max_length("new_key", &new_key, limits.key);
+    }
+
+    /// Fallible version of [`stdlib::encode_rotate_authentication_key_with_nonce_script`].
+    fn try_encode_rotate_authentication_key_with_nonce_script;
+    /// Version of [`try_encode_rotate_authentication_key_with_nonce_script`] checking `new_key`
+    /// against `limits`.
+    fn try_encode_rotate_authentication_key_with_nonce_script_with_limits
+        => encode_rotate_authentication_key_with_nonce_script(
+        sliding_nonce: SlidingNonce,
+        new_key: Vec<u8>,
+    ) |limits| {
+        validate_non_empty_key("new_key", &new_key);
+        validate_max_length("new_key", &new_key, limits.key);
+    }
+
+    /// Fallible version of [`stdlib::encode_rotate_authentication_key_with_nonce_admin_script`].
+    fn try_encode_rotate_authentication_key_with_nonce_admin_script;
+    /// Version of [`try_encode_rotate_authentication_key_with_nonce_admin_script`] checking
+    /// `new_key` against `limits`.
+    fn try_encode_rotate_authentication_key_with_nonce_admin_script_with_limits
+        => encode_rotate_authentication_key_with_nonce_admin_script(
+        sliding_nonce: SlidingNonce,
+        new_key: Vec<u8>,
+    ) |limits| {
+        validate_non_empty_key("new_key", &new_key);
+        validate_max_length("new_key", &new_key, limits.key);
+    }
+
+    /// Fallible version of [`stdlib::encode_rotate_authentication_key_with_recovery_address_script`].
+    fn try_encode_rotate_authentication_key_with_recovery_address_script;
+    /// Version of [`try_encode_rotate_authentication_key_with_recovery_address_script`] checking
+    /// `new_key` against `limits`.
+    fn try_encode_rotate_authentication_key_with_recovery_address_script_with_limits
+        => encode_rotate_authentication_key_with_recovery_address_script(
+        recovery_address: AccountAddress,
+        to_recover: AccountAddress,
+        new_key: Vec<u8>,
+    ) |limits| {
+        validate_non_empty_key("new_key", &new_key);
+        validate_max_length("new_key", &new_key, limits.key);
+    }
+
+    /// Fallible version of [`stdlib::encode_rotate_dual_attestation_info_script`].
+    fn try_encode_rotate_dual_attestation_info_script;
+    /// Version of [`try_encode_rotate_dual_attestation_info_script`] checking `new_url` and
+    /// `new_key` against `limits`.
+    fn try_encode_rotate_dual_attestation_info_script_with_limits
+        => encode_rotate_dual_attestation_info_script(
+        new_url: Vec<u8>,
+        new_key: Vec<u8>,
+    ) |limits| {
+        validate_non_empty_key("new_key", &new_key);
+        validate_max_length("new_url", &new_url, limits.url);
+        validate_max_length("new_key", &new_key, limits.key);
+    }
+}
+
+/// Fallible counterpart of [`crate::currencies::encode_batch_transfer`], which rejects an empty
+/// batch and transfers of zero coins.
+pub fn try_encode_batch_transfer<C: Into<TypeTag>>(
+    currency: C,
+    transfers: &[(AccountAddress, u64)],
+) -> Result<Vec<Script>, BuilderError> {
+    if transfers.is_empty() {
+        return Err(BuilderError::EmptyBatch);
+    }
+    if let Some(index) = transfers.iter().position(|(_, amount)| *amount == 0) {
+        return Err(BuilderError::ZeroAmount { index });
+    }
+    Ok(currencies::encode_batch_transfer(currency, transfers))
+}
+
+/// Fallible counterpart of [`crate::currencies::encode_add_currencies`], which rejects an empty
+/// list and currencies listed more than once, since adding a currency twice aborts.
+pub fn try_encode_add_currencies(currencies: &[TypeTag]) -> Result<Vec<Script>, BuilderError> {
+    if currencies.is_empty() {
+        return Err(BuilderError::EmptyBatch);
+    }
+    let mut seen = BTreeSet::new();
+    for currency in currencies {
+        if !seen.insert(currency) {
+            return Err(BuilderError::DuplicateCurrency {
+                currency: currency.clone(),
+            });
+        }
+    }
+    Ok(currencies::encode_add_currencies(currencies))
+}
+
+/// A `tiered_mint` to run right after a designated dealer is created, see
+/// [`try_encode_designated_dealer_onboarding`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InitialMint {
+    pub sliding_nonce: SlidingNonce,
+    pub mint_amount: u64,
+    pub tier_index: u64,
+}
+
+/// Encode the scripts that onboard a designated dealer at `addr`: its creation followed by
+/// `initial_mints`, to be sent in order by the treasury compliance account. Every script must use
+/// its own sliding nonce, otherwise all but the first would abort.
+pub fn try_encode_designated_dealer_onboarding(
+    currency: TypeTag,
+    sliding_nonce: SlidingNonce,
+    addr: AccountAddress,
+    auth_key_prefix: Vec<u8>,
+    human_name: Vec<u8>,
+    add_all_currencies: bool,
+    initial_mints: &[InitialMint],
+) -> Result<Vec<Script>, BuilderError> {
+    let mut nonces = BTreeSet::new();
+    nonces.insert(sliding_nonce);
+    for mint in initial_mints {
+        if !nonces.insert(mint.sliding_nonce) {
+            return Err(BuilderError::DuplicateSlidingNonce {
+                nonce: mint.sliding_nonce.get(),
+            });
+        }
+    }
+    let mut scripts = vec![try_encode_create_designated_dealer_script(
+        currency.clone(),
+        sliding_nonce,
+        addr,
+        auth_key_prefix,
+        human_name,
+        add_all_currencies,
+    )?];
+    for mint in initial_mints {
+        scripts.push(try_encode_tiered_mint_script(
+            currency.clone(),
+            mint.sliding_nonce,
+            addr,
+            mint.mint_amount,
+            mint.tier_index,
+        )?);
+    }
+    Ok(scripts)
+}
+
+/// Version of [`try_encode_rotate_dual_attestation_info_script`] taking the new URL as a string,
+/// which must be an `http` or `https` URL.
+pub fn try_encode_rotate_dual_attestation_info_with_url(
+    new_url: &str,
+    new_key: Vec<u8>,
+) -> Result<Script, BuilderError> {
+    validate_url(new_url)?;
+    try_encode_rotate_dual_attestation_info_script(new_url.as_bytes().to_vec(), new_key)
+}
+
+/// Narrow the amount passed as argument `field` to the `u64` taken by the scripts.
+pub fn narrow_amount(field: &'static str, amount: u128) -> Result<u64, BuilderError> {
+    if amount > u128::from(u64::MAX) {
+        return Err(BuilderError::AmountOverflow { field, amount });
+    }
+    Ok(amount as u64)
+}
+
+/// Version of [`try_encode_create_child_vasp_account_script`] taking a `u128` initial balance.
+pub fn try_encode_create_child_vasp_account_script_u128(
+    coin_type: TypeTag,
+    child_address: AccountAddress,
+    auth_key_prefix: Vec<u8>,
+    add_all_currencies: bool,
+    child_initial_balance: u128,
+) -> Result<Script, BuilderError> {
+    try_encode_create_child_vasp_account_script(
+        coin_type,
+        child_address,
+        auth_key_prefix,
+        add_all_currencies,
+        narrow_amount("child_initial_balance", child_initial_balance)?,
+    )
+}
+
+/// Version of [`try_encode_peer_to_peer_with_metadata_script`] taking a `u128` amount.
+pub fn try_encode_peer_to_peer_with_metadata_script_u128(
+    currency: TypeTag,
+    payee: AccountAddress,
+    amount: u128,
+    metadata: Vec<u8>,
+    metadata_signature: Vec<u8>,
+) -> Result<Script, BuilderError> {
+    try_encode_peer_to_peer_with_metadata_script(
+        currency,
+        payee,
+        narrow_amount("amount", amount)?,
+        metadata,
+        metadata_signature,
+    )
+}
+
+/// Version of [`try_encode_preburn_script`] taking a `u128` amount.
+pub fn try_encode_preburn_script_u128(
+    token: TypeTag,
+    amount: u128,
+) -> Result<Script, BuilderError> {
+    try_encode_preburn_script(token, narrow_amount("amount", amount)?)
+}
+
+/// Version of [`try_encode_tiered_mint_script`] taking a `u128` amount.
+pub fn try_encode_tiered_mint_script_u128(
+    coin_type: TypeTag,
+    sliding_nonce: SlidingNonce,
+    designated_dealer_address: AccountAddress,
+    mint_amount: u128,
+    tier_index: u64,
+) -> Result<Script, BuilderError> {
+    try_encode_tiered_mint_script(
+        coin_type,
+        sliding_nonce,
+        designated_dealer_address,
+        narrow_amount("mint_amount", mint_amount)?,
+        tier_index,
+    )
+}
+
+/// Check `type_args` and `args` against the parameters of `script` before they are handed to
+/// [`crate::stdlib_script::encode_stdlib_script`], which does no validation of its own.
+pub fn validate_script_args(
+    script: StdlibScript,
+    type_args: &[TypeTag],
+    args: &[TransactionArgument],
+) -> Result<(), BuilderError> {
+    let schema = argument_schema(script);
+    if type_args.len() != schema.type_arg_count {
+        return Err(BuilderError::WrongArgumentCount {
+            script: script.name(),
+            kind: "type arguments",
+            got: type_args.len(),
+            expected: schema.type_arg_count,
+        });
+    }
+    expect_args(args, schema)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diem_crypto::{ed25519::Ed25519PrivateKey, PrivateKey};
+
+    fn nonce(sliding_nonce: u64) -> SlidingNonce {
+        SlidingNonce::new(sliding_nonce).unwrap()
+    }
+
+    #[test]
+    fn test_validate_auth_key_prefix() {
+        assert_eq!(
+            validate_auth_key_prefix(&[0u8; AUTH_KEY_PREFIX_LENGTH]),
+            Ok(())
+        );
+        assert_eq!(
+            validate_auth_key_prefix(&[]),
+            Err(BuilderError::BadAuthKeyPrefixLength {
+                got: 0,
+                expected: AUTH_KEY_PREFIX_LENGTH,
+            })
+        );
+        assert_eq!(
+            try_encode_create_validator_account_script(
+                nonce(1),
+                AccountAddress::ZERO,
+                vec![0u8; AuthenticationKey::LENGTH],
+                b"validator".to_vec(),
+            ),
+            Err(BuilderError::BadAuthKeyPrefixLength {
+                got: AuthenticationKey::LENGTH,
+                expected: AUTH_KEY_PREFIX_LENGTH,
+            })
+        );
+    }
+
+    #[test]
+    fn test_auth_key_prefix() {
+        for _ in 0..10 {
+            let key = AuthenticationKey::random();
+            let prefix = auth_key_prefix(&key);
+            assert_eq!(validate_auth_key_prefix(&prefix), Ok(()));
+            assert_eq!(
+                [prefix.as_slice(), key.derived_address().as_ref()].concat(),
+                key.to_vec()
+            );
+        }
+    }
+
+    #[test]
+    fn test_auth_key_and_address() {
+        let private_key = Ed25519PrivateKey::try_from(&[1u8; 32][..]).unwrap();
+        let public_key = private_key.public_key();
+        let (auth_key_prefix, address) = auth_key_and_address(&public_key);
+        assert_eq!(validate_auth_key_prefix(&auth_key_prefix), Ok(()));
+        assert_eq!(
+            [auth_key_prefix.as_slice(), address.as_ref()].concat(),
+            AuthenticationKey::ed25519(&public_key).to_vec()
+        );
+        assert!(try_encode_create_parent_vasp_account_script(
+            TypeTag::Bool,
+            nonce(1),
+            address,
+            auth_key_prefix,
+            vec![],
+            false,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_tier_index_bounds() {
+        for tier_index in 0..=3 {
+            assert!(try_encode_tiered_mint_script(
+                TypeTag::Bool,
+                nonce(1),
+                AccountAddress::ZERO,
+                100,
+                tier_index
+            )
+            .is_ok());
+        }
+        assert_eq!(
+            try_encode_tiered_mint_script(TypeTag::Bool, nonce(1), AccountAddress::ZERO, 100, 4),
+            Err(BuilderError::TierIndexOutOfRange {
+                tier_index: 4,
+                max: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_encode_rotate_authentication_key_from_public_key() {
+        let public_key = Ed25519PrivateKey::try_from(&[1u8; 32][..])
+            .unwrap()
+            .public_key();
+        let script = encode_rotate_authentication_key_from_public_key(&public_key);
+        assert_eq!(
+            script.args(),
+            &[TransactionArgument::U8Vector(
+                AuthenticationKey::ed25519(&public_key).to_vec()
+            )]
+        );
+        assert_ne!(
+            script,
+            stdlib::encode_rotate_authentication_key_script(public_key.to_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn test_empty_key_rejected() {
+        assert_eq!(
+            try_encode_rotate_authentication_key_script(vec![]),
+            Err(BuilderError::EmptyKey { field: "new_key" })
+        );
+        assert_eq!(
+            try_encode_rotate_authentication_key_script(vec![1; AuthenticationKey::LENGTH]),
+            Ok(stdlib::encode_rotate_authentication_key_script(vec![
+                1;
+                AuthenticationKey::LENGTH
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_consensus_pubkey_checks() {
+        let validator = AccountAddress::random();
+        let consensus_pubkey = vec![1; ED25519_PUBLIC_KEY_LENGTH];
+        assert_eq!(
+            try_encode_register_validator_config_script(
+                validator,
+                consensus_pubkey.clone(),
+                vec![2],
+                vec![3],
+            ),
+            Ok(stdlib::encode_register_validator_config_script(
+                validator,
+                consensus_pubkey.clone(),
+                vec![2],
+                vec![3],
+            ))
+        );
+        assert_eq!(
+            try_encode_register_validator_config_script(validator, vec![], vec![2], vec![3]),
+            Err(BuilderError::EmptyKey {
+                field: "consensus_pubkey"
+            })
+        );
+        assert_eq!(
+            try_encode_set_validator_config_and_reconfigure_script(
+                validator,
+                consensus_pubkey[1..].to_vec(),
+                vec![2],
+                vec![3],
+            ),
+            Err(BuilderError::BadKeyLength {
+                field: "consensus_pubkey",
+                got: ED25519_PUBLIC_KEY_LENGTH - 1,
+                expected: ED25519_PUBLIC_KEY_LENGTH,
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_shared_public_key() {
+        let public_key = Ed25519PrivateKey::try_from(&[1u8; 32][..])
+            .unwrap()
+            .public_key();
+        assert_eq!(
+            validate_shared_public_key(&public_key.to_bytes()),
+            Ok(public_key)
+        );
+        assert_eq!(
+            validate_shared_public_key(&[1; 31]),
+            Err(BuilderError::BadKeyLength {
+                field: "public_key",
+                got: 31,
+                expected: ED25519_PUBLIC_KEY_LENGTH,
+            })
+        );
+    }
+
+    #[test]
+    fn test_shared_key_length() {
+        let public_key = vec![1; ED25519_PUBLIC_KEY_LENGTH];
+        assert_eq!(
+            try_encode_publish_shared_ed25519_public_key_script(public_key.clone()),
+            Ok(stdlib::encode_publish_shared_ed25519_public_key_script(
+                public_key.clone()
+            ))
+        );
+        assert_eq!(
+            try_encode_rotate_shared_ed25519_public_key_script(public_key[1..].to_vec()),
+            Err(BuilderError::BadKeyLength {
+                field: "public_key",
+                got: ED25519_PUBLIC_KEY_LENGTH - 1,
+                expected: ED25519_PUBLIC_KEY_LENGTH,
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_script_args() {
+        let args = [
+            TransactionArgument::Address(AccountAddress::ZERO),
+            TransactionArgument::U64(10),
+            TransactionArgument::U8Vector(vec![]),
+            TransactionArgument::U8Vector(vec![]),
+        ];
+        let script = StdlibScript::PeerToPeerWithMetadata;
+        assert_eq!(
+            validate_script_args(script, &[TypeTag::Bool], &args),
+            Ok(())
+        );
+        assert_eq!(
+            validate_script_args(script, &[], &args),
+            Err(BuilderError::WrongArgumentCount {
+                script: "peer_to_peer_with_metadata",
+                kind: "type arguments",
+                got: 0,
+                expected: 1,
+            })
+        );
+        assert_eq!(
+            validate_script_args(script, &[TypeTag::Bool], &args[..3]),
+            Err(BuilderError::WrongArgumentCount {
+                script: "peer_to_peer_with_metadata",
+                kind: "arguments",
+                got: 3,
+                expected: 4,
+            })
+        );
+        assert_eq!(
+            validate_script_args(
+                script,
+                &[TypeTag::Bool],
+                &[
+                    TransactionArgument::Address(AccountAddress::ZERO),
+                    TransactionArgument::U128(10),
+                    TransactionArgument::U8Vector(vec![]),
+                    TransactionArgument::U8Vector(vec![]),
+                ]
+            ),
+            Err(BuilderError::WrongArgumentType {
+                script: "peer_to_peer_with_metadata",
+                name: "amount",
+                got: "u128",
+                expected: "u64",
+            })
+        );
+    }
+
+    #[test]
+    fn test_argument_length() {
+        assert_eq!(validate_max_length("metadata", &[0; 4], 4), Ok(()));
+        assert_eq!(
+            validate_max_length("metadata", &[0; 5], 4),
+            Err(BuilderError::ArgTooLong {
+                field: "metadata",
+                len: 5,
+                max: 4
+            })
+        );
+        let metadata = vec![0; MAX_METADATA_LENGTH + 1];
+        assert_eq!(
+            try_encode_peer_to_peer_with_metadata_script(
+                TypeTag::Bool,
+                AccountAddress::random(),
+                1,
+                metadata.clone(),
+                vec![]
+            ),
+            Err(BuilderError::ArgTooLong {
+                field: "metadata",
+                len: MAX_METADATA_LENGTH + 1,
+                max: MAX_METADATA_LENGTH
+            })
+        );
+        let limits = ArgumentLimits {
+            metadata: MAX_METADATA_LENGTH + 1,
+            ..ArgumentLimits::default()
+        };
+        assert!(try_encode_peer_to_peer_with_metadata_script_with_limits(
+            TypeTag::Bool,
+            AccountAddress::random(),
+            1,
+            metadata,
+            vec![],
+            &limits,
+        )
+        .is_ok());
+        assert_eq!(
+            try_encode_rotate_dual_attestation_info_script(
+                vec![b'a'; MAX_URL_LENGTH + 1],
+                vec![1; ED25519_PUBLIC_KEY_LENGTH]
+            ),
+            Err(BuilderError::ArgTooLong {
+                field: "new_url",
+                len: MAX_URL_LENGTH + 1,
+                max: MAX_URL_LENGTH
+            })
+        );
+        assert_eq!(
+            try_encode_rotate_authentication_key_script(vec![1; MAX_KEY_LENGTH + 1]),
+            Err(BuilderError::ArgTooLong {
+                field: "new_key",
+                len: MAX_KEY_LENGTH + 1,
+                max: MAX_KEY_LENGTH
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_diem_version() {
+        assert_eq!(
+            try_encode_update_diem_version_script(nonce(1), 2),
+            Ok(stdlib::encode_update_diem_version_script(1, 2))
+        );
+        assert_eq!(
+            try_encode_update_diem_version_script(nonce(1), 0),
+            Err(BuilderError::InvalidDiemVersion { major: 0 })
+        );
+    }
+
+    #[test]
+    fn test_validate_sliding_nonce() {
+        let account = AccountAddress::random();
+        assert_eq!(
+            try_encode_freeze_account_script(nonce(1), account),
+            Ok(stdlib::encode_freeze_account_script(1, account))
+        );
+        assert_eq!(SlidingNonce::new(0), Err(BuilderError::ZeroSlidingNonce));
+    }
+
+    #[test]
+    fn test_exchange_rate_denominator() {
+        assert_eq!(
+            try_encode_update_exchange_rate_script(TypeTag::Bool, nonce(1), 1, 2),
+            Ok(stdlib::encode_update_exchange_rate_script(
+                TypeTag::Bool,
+                1,
+                1,
+                2
+            ))
+        );
+        assert_eq!(
+            try_encode_update_exchange_rate_script(TypeTag::Bool, nonce(1), 1, 0),
+            Err(BuilderError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_rotate_dual_attestation_info_with_url() {
+        let key = vec![1; ED25519_PUBLIC_KEY_LENGTH];
+        assert_eq!(
+            try_encode_rotate_dual_attestation_info_with_url("https://vasp.com/", key.clone()),
+            Ok(stdlib::encode_rotate_dual_attestation_info_script(
+                b"https://vasp.com/".to_vec(),
+                key.clone()
+            ))
+        );
+        for url in &["", "vasp.com", "ftp://vasp.com", "https://"] {
+            assert!(matches!(
+                try_encode_rotate_dual_attestation_info_with_url(url, key.clone()),
+                Err(BuilderError::InvalidUrl { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_zero_mint_and_preburn_amounts() {
+        assert_eq!(
+            try_encode_preburn_script(TypeTag::Bool, 0),
+            Err(BuilderError::ZeroArgument { field: "amount" })
+        );
+        assert_eq!(
+            try_encode_tiered_mint_script(TypeTag::Bool, nonce(1), AccountAddress::random(), 0, 0),
+            Err(BuilderError::ZeroArgument {
+                field: "mint_amount"
+            })
+        );
+    }
+
+    #[test]
+    fn test_u128_amounts() {
+        assert_eq!(narrow_amount("amount", u64::MAX.into()), Ok(u64::MAX));
+        assert_eq!(
+            try_encode_preburn_script_u128(TypeTag::Bool, 10),
+            Ok(stdlib::encode_preburn_script(TypeTag::Bool, 10))
+        );
+        let amount = u128::from(u64::MAX) + 1;
+        assert_eq!(
+            try_encode_preburn_script_u128(TypeTag::Bool, amount),
+            Err(BuilderError::AmountOverflow {
+                field: "amount",
+                amount
+            })
+        );
+        assert_eq!(
+            try_encode_tiered_mint_script_u128(
+                TypeTag::Bool,
+                nonce(1),
+                AccountAddress::random(),
+                amount,
+                0
+            ),
+            Err(BuilderError::AmountOverflow {
+                field: "mint_amount",
+                amount
+            })
+        );
+    }
+
+    #[test]
+    fn test_add_currency_rejects_non_currency() {
+        assert_eq!(
+            try_encode_add_currency_to_account_script(currencies::Currency::XUS.into()),
+            Ok(stdlib::encode_add_currency_to_account_script(
+                currencies::Currency::XUS.into()
+            ))
+        );
+        assert!(matches!(
+            try_encode_add_currency_to_account_script(TypeTag::Bool),
+            Err(BuilderError::BadCurrencyCode { .. })
+        ));
+    }
+
+    #[test]
+    fn test_try_encode_designated_dealer_onboarding() {
+        let addr = AccountAddress::random();
+        let mint = InitialMint {
+            sliding_nonce: nonce(2),
+            mint_amount: 100,
+            tier_index: 1,
+        };
+        assert_eq!(
+            try_encode_designated_dealer_onboarding(
+                TypeTag::Bool,
+                nonce(1),
+                addr,
+                vec![0; AUTH_KEY_PREFIX_LENGTH],
+                b"dd".to_vec(),
+                false,
+                &[mint],
+            ),
+            Ok(vec![
+                stdlib::encode_create_designated_dealer_script(
+                    TypeTag::Bool,
+                    1,
+                    addr,
+                    vec![0; AUTH_KEY_PREFIX_LENGTH],
+                    b"dd".to_vec(),
+                    false,
+                ),
+                stdlib::encode_tiered_mint_script(TypeTag::Bool, 2, addr, 100, 1),
+            ])
+        );
+        assert_eq!(
+            try_encode_designated_dealer_onboarding(
+                TypeTag::Bool,
+                nonce(2),
+                addr,
+                vec![0; AUTH_KEY_PREFIX_LENGTH],
+                b"dd".to_vec(),
+                false,
+                &[mint],
+            ),
+            Err(BuilderError::DuplicateSlidingNonce { nonce: 2 })
+        );
+    }
+
+    #[test]
+    fn test_try_encode_batch_transfer() {
+        let payees = [AccountAddress::random(), AccountAddress::random()];
+        assert_eq!(
+            try_encode_batch_transfer(TypeTag::Bool, &[(payees[0], 1), (payees[1], 2)]),
+            Ok(vec![
+                stdlib::encode_peer_to_peer_with_metadata_script(
+                    TypeTag::Bool,
+                    payees[0],
+                    1,
+                    vec![],
+                    vec![],
+                ),
+                stdlib::encode_peer_to_peer_with_metadata_script(
+                    TypeTag::Bool,
+                    payees[1],
+                    2,
+                    vec![],
+                    vec![],
+                ),
+            ])
+        );
+        assert_eq!(
+            try_encode_batch_transfer(TypeTag::Bool, &[]),
+            Err(BuilderError::EmptyBatch)
+        );
+        assert_eq!(
+            try_encode_batch_transfer(TypeTag::Bool, &[(payees[0], 1), (payees[1], 0)]),
+            Err(BuilderError::ZeroAmount { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_validate_arg_count() {
+        let script = |count| Script::new(vec![], vec![], vec![TransactionArgument::U8(0); count]);
+        assert_eq!(
+            validate_arg_count(&script(MAX_SCRIPT_ARGS), MAX_SCRIPT_ARGS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_arg_count(&script(MAX_SCRIPT_ARGS + 1), MAX_SCRIPT_ARGS),
+            Err(BuilderError::TooManyArgs {
+                count: MAX_SCRIPT_ARGS + 1,
+                max: MAX_SCRIPT_ARGS
+            })
+        );
+        assert_eq!(validate_arg_count(&script(0), 0), Ok(()));
+        assert_eq!(
+            validate_arg_count(&script(1), 0),
+            Err(BuilderError::TooManyArgs { count: 1, max: 0 })
+        );
+    }
+
+    #[test]
+    fn test_try_encode_add_currencies() {
+        let tags = [
+            currencies::Currency::XDX.type_tag(),
+            currencies::Currency::XUS.type_tag(),
+        ];
+        assert_eq!(
+            try_encode_add_currencies(&tags),
+            Ok(vec![
+                stdlib::encode_add_currency_to_account_script(currencies::Currency::XDX.type_tag()),
+                stdlib::encode_add_currency_to_account_script(currencies::Currency::XUS.type_tag()),
+            ])
+        );
+        assert_eq!(
+            try_encode_add_currencies(&[]),
+            Err(BuilderError::EmptyBatch)
+        );
+        assert_eq!(
+            try_encode_add_currencies(&[
+                currencies::Currency::XDX.type_tag(),
+                currencies::Currency::XDX.type_tag()
+            ]),
+            Err(BuilderError::DuplicateCurrency {
+                currency: currencies::Currency::XDX.type_tag()
+            })
+        );
+    }
+}
@@ -3,5 +3,36 @@
 
 #![forbid(unsafe_code)]
 
+//! Builders for the Diem transaction scripts and script functions.
+//!
+//! The [`stdlib`] module is generated from the script ABIs and provides one `encode_*` function
+//! per script, along with the structured [`stdlib::ScriptCall`] enum. A `Script` can be mapped
+//! back into its typed arguments with [`stdlib::ScriptCall::decode`], which returns `None` for
+//! unknown code or for arguments that do not match the expected layout.
+//!
+//! This crate requires `std`. The `Script`, `TypeTag` and `AccountAddress` types it builds come
+//! from `diem-types` and `move-core-types`, neither of which supports `no_std`, so a `no_std`
+//! build of the encoders would first need those crates to be split into `alloc`-only cores.
+
+pub mod builders;
+pub mod by_ref;
+pub mod currencies;
+pub mod dual_attestation;
+pub mod error;
 pub mod error_explain;
+pub mod fallible;
+pub mod metadata;
+pub mod parse;
+pub mod prelude;
+pub mod pretty;
+pub mod publishing_option;
+pub mod script_encoder;
+pub mod script_json;
+pub mod sliding_nonce;
 pub mod stdlib;
+pub mod stdlib_script;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod transaction_kind;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,50 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The `metadata` argument of `peer_to_peer_with_metadata` scripts for regular transfers, i.e.
+//! BCS-encoded `Metadata::GeneralMetadata`, which carries the subaddresses of the custodial
+//! accounts involved and the payment being refunded, if any.
+
+use diem_types::transaction::metadata::{GeneralMetadata, GeneralMetadataV0, Metadata};
+
+/// Build the `metadata` argument of a transfer from `from_subaddress` to `to_subaddress`,
+/// referring to the sent payment event `referenced_event` if it is a refund.
+pub fn general_metadata(
+    to_subaddress: Option<Vec<u8>>,
+    from_subaddress: Option<Vec<u8>>,
+    referenced_event: Option<u64>,
+) -> Vec<u8> {
+    let metadata = Metadata::GeneralMetadata(GeneralMetadata::GeneralMetadataVersion0(
+        GeneralMetadataV0::new(to_subaddress, from_subaddress, referenced_event),
+    ));
+    bcs::to_bytes(&metadata).expect("Metadata is serializable")
+}
+
+/// Decode the `metadata` argument of a transfer built with [`general_metadata`], or return `None`
+/// if it holds another kind of metadata or cannot be decoded.
+pub fn decode_general_metadata(metadata: &[u8]) -> Option<GeneralMetadataV0> {
+    match bcs::from_bytes(metadata).ok()? {
+        Metadata::GeneralMetadata(GeneralMetadata::GeneralMetadataVersion0(general)) => {
+            Some(general)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_general_metadata_round_trip() {
+        let metadata = general_metadata(Some(vec![1; 8]), None, Some(3));
+        let decoded = decode_general_metadata(&metadata).unwrap();
+        assert_eq!(decoded.to_subaddress(), &Some(vec![1; 8]));
+        assert_eq!(decoded.from_subaddress(), &None);
+        assert_eq!(decoded.referenced_event(), &Some(3));
+
+        let undefined = bcs::to_bytes(&Metadata::Undefined).unwrap();
+        assert_eq!(decode_general_metadata(&undefined), None);
+        assert_eq!(decode_general_metadata(&[0xff]), None);
+    }
+}
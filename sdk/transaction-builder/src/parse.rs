@@ -0,0 +1,92 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parsing of typed transaction arguments from text, for CLIs and config-driven tooling.
+//!
+//! Arguments are written as `<type>:<value>`, e.g. `u64:100`, `address:0x1`, `bool:true` or
+//! `bytes:0xdeadbeef`. The supported types are `u8`, `u64`, `u128`, `address`, `bool` and
+//! `bytes`.
+
+use crate::error::BuilderError;
+use diem_types::{account_address::AccountAddress, transaction::TransactionArgument};
+use std::fmt::Display;
+
+/// Parse a single `<type>:<value>` argument.
+pub fn parse_transaction_argument(s: &str) -> Result<TransactionArgument, BuilderError> {
+    let invalid = |reason: &dyn Display| BuilderError::InvalidArgument {
+        input: s.to_string(),
+        reason: reason.to_string(),
+    };
+    let (ty, value) = s
+        .split_once(':')
+        .ok_or_else(|| invalid(&"expected `<type>:<value>`"))?;
+    Ok(match ty {
+        "u8" => TransactionArgument::U8(value.parse().map_err(|e| invalid(&e))?),
+        "u64" => TransactionArgument::U64(value.parse().map_err(|e| invalid(&e))?),
+        "u128" => TransactionArgument::U128(value.parse().map_err(|e| invalid(&e))?),
+        "bool" => TransactionArgument::Bool(value.parse().map_err(|e| invalid(&e))?),
+        "address" => TransactionArgument::Address(
+            AccountAddress::from_hex_literal(value).map_err(|e| invalid(&e))?,
+        ),
+        "bytes" => TransactionArgument::U8Vector(
+            hex::decode(value.strip_prefix("0x").unwrap_or(value)).map_err(|e| invalid(&e))?,
+        ),
+        _ => return Err(invalid(&format!("unknown type `{}`", ty))),
+    })
+}
+
+/// Parse a list of `<type>:<value>` arguments, stopping at the first malformed one.
+pub fn parse_transaction_arguments<S: AsRef<str>>(
+    args: &[S],
+) -> Result<Vec<TransactionArgument>, BuilderError> {
+    args.iter()
+        .map(|arg| parse_transaction_argument(arg.as_ref()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_transaction_arguments() {
+        assert_eq!(
+            parse_transaction_arguments(&[
+                "u8:1",
+                "u64:100",
+                "u128:340282366920938463463374607431768211455",
+                "bool:true",
+                "address:0x1",
+                "bytes:0xdeadbeef",
+                "bytes:",
+            ]),
+            Ok(vec![
+                TransactionArgument::U8(1),
+                TransactionArgument::U64(100),
+                TransactionArgument::U128(u128::MAX),
+                TransactionArgument::Bool(true),
+                TransactionArgument::Address(AccountAddress::from_hex_literal("0x1").unwrap()),
+                TransactionArgument::U8Vector(vec![0xde, 0xad, 0xbe, 0xef]),
+                TransactionArgument::U8Vector(vec![]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed_arguments() {
+        for input in &[
+            "100",
+            "u8:256",
+            "u64:-1",
+            "bool:yes",
+            "address:0xg",
+            "bytes:0xabc",
+            "string:abc",
+        ] {
+            match parse_transaction_argument(input) {
+                Err(BuilderError::InvalidArgument { input: got, .. }) => assert_eq!(got, *input),
+                other => panic!("Expected {} to be rejected, got {:?}", input, other),
+            }
+        }
+    }
+}
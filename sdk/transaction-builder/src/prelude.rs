@@ -0,0 +1,44 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The types and encoders needed to build the common scripts, so that a single glob import is
+//! enough for most callers.
+//!
+//! ## Example
+//! ```
+//! use diem_transaction_builder::prelude::*;
+//!
+//! let payee = AccountAddress::from_hex_literal("0xdd").unwrap();
+//! let script: Script = P2PTransferBuilder::new()
+//!     .coin_type(Currency::XUS.into())
+//!     .recipient(payee)
+//!     .amount(10)
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(script, encode_peer_to_peer_for(Currency::XUS, payee, 10));
+//! ```
+
+pub use crate::{
+    builders::{
+        ChildVaspBuilder, P2PTransferBuilder, ParentVaspBuilder, RawTransactionBuilder,
+        SharedKeySetup,
+    },
+    currencies::{encode_peer_to_peer_for, Currency},
+    error::BuilderError,
+    fallible::{
+        encode_rotate_authentication_key_from_public_key,
+        try_encode_peer_to_peer_with_metadata_script,
+    },
+    sliding_nonce::SlidingNonce,
+    stdlib::{
+        encode_create_child_vasp_account_script, encode_create_parent_vasp_account_script,
+        encode_peer_to_peer_with_metadata_script, encode_rotate_authentication_key_script,
+        ScriptCall,
+    },
+    stdlib_script::StdlibScript,
+};
+pub use diem_types::{
+    account_address::AccountAddress,
+    transaction::{Script, TransactionArgument},
+};
+pub use move_core_types::language_storage::TypeTag;
@@ -0,0 +1,341 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! One-line rendering of scripts for logs, e.g.
+//! `peer_to_peer_with_metadata<..>(payee=0x.., amount=100, metadata=0x, ..)`.
+//! [`PrettyScript::redacted`] and [`signing_preview_redacted`] hide keys and signatures.
+
+use crate::{
+    stdlib::ScriptCall,
+    stdlib_script::{argument_schema, script_currencies, script_hash, StdlibScript},
+};
+use diem_types::{
+    account_config::currency_code_from_type_tag,
+    transaction::{Script, TransactionArgument},
+};
+use std::fmt;
+
+/// Displays a script as its name, type arguments and named arguments. Scripts that fail to decode
+/// are displayed as the hash of their code followed by their raw arguments.
+#[derive(Clone, Copy, Debug)]
+pub struct PrettyScript<'a>(pub &'a Script);
+
+impl fmt::Display for PrettyScript<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stdlib_script =
+            ScriptCall::decode(self.0).and_then(|call| StdlibScript::from_name(call.name()));
+        fmt::Display::fmt(&NamedScript(self.0, stdlib_script, false), f)
+    }
+}
+
+impl<'a> PrettyScript<'a> {
+    /// Displays the script like [`PrettyScript`], except that the arguments marked as sensitive in
+    /// the [`argument_schema`] of the script, i.e. keys and signatures, are replaced by `***`.
+    pub fn redacted(self) -> RedactedScript<'a> {
+        RedactedScript(self.0)
+    }
+}
+
+/// See [`PrettyScript::redacted`].
+#[derive(Clone, Copy, Debug)]
+pub struct RedactedScript<'a>(pub &'a Script);
+
+impl fmt::Display for RedactedScript<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stdlib_script =
+            ScriptCall::decode(self.0).and_then(|call| StdlibScript::from_name(call.name()));
+        fmt::Display::fmt(&NamedScript(self.0, stdlib_script, true), f)
+    }
+}
+
+/// Encode `call` along with its rendering by [`PrettyScript`], e.g. for an audit trail, without
+/// decoding the script back.
+pub fn encode_with_description(call: ScriptCall) -> (Script, String) {
+    let stdlib_script = StdlibScript::from_name(call.name());
+    let script = call.encode();
+    let description = NamedScript(&script, stdlib_script, false).to_string();
+    (script, description)
+}
+
+/// A script already known to call `stdlib_script`, or an unknown script if `None`, with its
+/// sensitive arguments redacted if the flag is set.
+struct NamedScript<'a>(&'a Script, Option<StdlibScript>, bool);
+
+impl fmt::Display for NamedScript<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let NamedScript(script, stdlib_script, redact) = *self;
+        match stdlib_script {
+            Some(stdlib_script) => write!(f, "{}", stdlib_script.name())?,
+            None => write!(f, "<unknown {}>", script_hash(script))?,
+        }
+        if !script.ty_args().is_empty() {
+            write!(f, "<")?;
+            for (i, type_arg) in script.ty_args().iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", type_arg)?;
+            }
+            write!(f, ">")?;
+        }
+        write!(f, "(")?;
+        for (i, arg) in script.args().iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            if let Some(name) = stdlib_script.and_then(|s| s.arg_names().get(i)) {
+                write!(f, "{}=", name)?;
+            }
+            if redact && is_sensitive(stdlib_script, i) {
+                write!(f, "***")?;
+            } else {
+                write_argument(f, arg)?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+/// Maximum length, in characters, of a [`signing_preview`].
+pub const MAX_SIGNING_PREVIEW_LENGTH: usize = 160;
+
+/// Return a one-line summary of `script` to show before it is signed, e.g. on a hardware wallet,
+/// of at most `MAX_SIGNING_PREVIEW_LENGTH` characters.
+///
+/// Amounts are followed by the code of the currency the script operates on, or else by
+/// `currency_hint`, and byte vectors are shown by length only. A hint that differs from the
+/// currency of the script is shown as a mismatch rather than trusted. Unknown scripts start with
+/// the hash of their code, so that a tampered script can still be told apart.
+pub fn signing_preview(script: &Script, currency_hint: Option<&str>) -> String {
+    preview(script, currency_hint, false)
+}
+
+/// Return the [`signing_preview`] of `script` with its keys and signatures replaced by `***`, so
+/// that it can be logged.
+pub fn signing_preview_redacted(script: &Script) -> String {
+    preview(script, None, true)
+}
+
+fn preview(script: &Script, currency_hint: Option<&str>, redact: bool) -> String {
+    let stdlib_script = StdlibScript::from_code(script.code());
+    let script_currency = script_currencies(script)
+        .into_iter()
+        .next()
+        .and_then(|type_tag| currency_code_from_type_tag(type_tag).ok())
+        .map(|code| code.into_string());
+    let currency = match (script_currency, currency_hint) {
+        (Some(currency), Some(hint)) if currency != hint => {
+            Some(format!("{} (mismatch: expected {})", currency, hint))
+        }
+        (Some(currency), _) => Some(currency),
+        (None, hint) => hint.map(str::to_string),
+    };
+    let args: Vec<_> = script
+        .args()
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| {
+            let name = stdlib_script.and_then(|s| s.arg_names().get(i).copied());
+            let value = match (arg, &currency) {
+                (TransactionArgument::U64(amount), Some(currency))
+                    if name.map_or(false, |name| {
+                        name.contains("amount") || name.contains("balance")
+                    }) =>
+                {
+                    format!("{} {}", amount, currency)
+                }
+                _ if redact && is_sensitive(stdlib_script, i) => "***".to_string(),
+                (TransactionArgument::U8Vector(bytes), _) => format!("<{} bytes>", bytes.len()),
+                _ => PrettyArgument(arg).to_string(),
+            };
+            match name {
+                Some(name) => format!("{}={}", name, value),
+                None => value,
+            }
+        })
+        .collect();
+    let preview = match stdlib_script {
+        Some(stdlib_script) => format!("{}({})", stdlib_script.name(), args.join(", ")),
+        None => format!(
+            "unknown script {}({})",
+            script_hash(script),
+            args.join(", ")
+        ),
+    };
+    if preview.chars().count() <= MAX_SIGNING_PREVIEW_LENGTH {
+        return preview;
+    }
+    let mut truncated: String = preview
+        .chars()
+        .take(MAX_SIGNING_PREVIEW_LENGTH - 3)
+        .collect();
+    truncated.push_str("...");
+    truncated
+}
+
+fn is_sensitive(stdlib_script: Option<StdlibScript>, index: usize) -> bool {
+    stdlib_script.map_or(false, |s| argument_schema(s).is_sensitive(index))
+}
+
+struct PrettyArgument<'a>(&'a TransactionArgument);
+
+impl fmt::Display for PrettyArgument<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_argument(f, self.0)
+    }
+}
+
+fn write_argument(f: &mut fmt::Formatter<'_>, arg: &TransactionArgument) -> fmt::Result {
+    match arg {
+        TransactionArgument::U8(value) => write!(f, "{}u8", value),
+        TransactionArgument::U64(value) => write!(f, "{}", value),
+        TransactionArgument::U128(value) => write!(f, "{}u128", value),
+        TransactionArgument::Address(address) => write!(f, "0x{}", address.to_hex()),
+        TransactionArgument::U8Vector(bytes) => write!(f, "0x{}", hex::encode(bytes)),
+        TransactionArgument::AddressVector(addresses) => {
+            write!(f, "[")?;
+            for (i, address) in addresses.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "0x{}", address.to_hex())?;
+            }
+            write!(f, "]")
+        }
+        TransactionArgument::Bool(value) => write!(f, "{}", value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdlib;
+    use diem_types::{
+        account_address::AccountAddress,
+        account_config::{gas_type_tag, GAS_NAME},
+    };
+    use move_core_types::language_storage::TypeTag;
+
+    #[test]
+    fn test_pretty_known_script() {
+        let payee = AccountAddress::random();
+        let script = stdlib::encode_peer_to_peer_with_metadata_script(
+            TypeTag::Bool,
+            payee,
+            100,
+            vec![1, 2],
+            vec![],
+        );
+        assert_eq!(
+            PrettyScript(&script).to_string(),
+            format!(
+                "peer_to_peer_with_metadata<Bool>(payee=0x{}, amount=100, metadata=0x0102, \
+                 metadata_signature=0x)",
+                payee.to_hex()
+            )
+        );
+    }
+
+    #[test]
+    fn test_encode_with_description() {
+        let call = ScriptCall::Preburn {
+            token: TypeTag::Bool,
+            amount: 10,
+        };
+        let (script, description) = encode_with_description(call.clone());
+        assert_eq!(script, call.encode());
+        assert_eq!(description, "preburn<Bool>(amount=10)");
+        assert_eq!(description, PrettyScript(&script).to_string());
+    }
+
+    #[test]
+    fn test_signing_preview() {
+        let payee = AccountAddress::random();
+        let script = stdlib::encode_peer_to_peer_with_metadata_script(
+            gas_type_tag(),
+            payee,
+            100,
+            vec![1, 2],
+            vec![],
+        );
+        let expected = format!(
+            "peer_to_peer_with_metadata(payee=0x{}, amount=100 {}, metadata=<2 bytes>, \
+             metadata_signature=<0 bytes>)",
+            payee.to_hex(),
+            GAS_NAME
+        );
+        assert_eq!(signing_preview(&script, None), expected);
+        assert_eq!(signing_preview(&script, Some(GAS_NAME)), expected);
+        assert!(signing_preview(&script, Some("XUS"))
+            .contains(&format!("amount=100 {} (mismatch: expected XUS)", GAS_NAME)));
+
+        let script = stdlib::encode_peer_to_peer_with_metadata_script(
+            TypeTag::Bool,
+            payee,
+            100,
+            vec![],
+            vec![],
+        );
+        assert!(signing_preview(&script, Some("XUS")).contains("amount=100 XUS"));
+
+        let script = Script::new(
+            vec![0xca, 0xfe],
+            vec![],
+            vec![TransactionArgument::U64(7); 20],
+        );
+        let preview = signing_preview(&script, None);
+        assert!(preview.starts_with(&format!("unknown script {}", script_hash(&script))));
+        assert_eq!(preview.chars().count(), MAX_SIGNING_PREVIEW_LENGTH);
+        assert!(preview.ends_with("..."));
+    }
+
+    #[test]
+    fn test_redacted() {
+        let payee = AccountAddress::random();
+        let script = stdlib::encode_peer_to_peer_with_metadata_script(
+            gas_type_tag(),
+            payee,
+            100,
+            vec![1, 2],
+            vec![3; 64],
+        );
+        assert_eq!(
+            PrettyScript(&script).redacted().to_string(),
+            format!(
+                "peer_to_peer_with_metadata<{}>(payee=0x{}, amount=100, metadata=0x0102, \
+                 metadata_signature=***)",
+                gas_type_tag(),
+                payee.to_hex()
+            )
+        );
+        assert_eq!(
+            signing_preview_redacted(&script),
+            format!(
+                "peer_to_peer_with_metadata(payee=0x{}, amount=100 {}, metadata=<2 bytes>, \
+                 metadata_signature=***)",
+                payee.to_hex(),
+                GAS_NAME
+            )
+        );
+
+        let script = stdlib::encode_rotate_authentication_key_script(vec![7; 32]);
+        assert_eq!(
+            PrettyScript(&script).redacted().to_string(),
+            "rotate_authentication_key(new_key=***)"
+        );
+        let script = Script::new(vec![0xca, 0xfe], vec![], vec![TransactionArgument::U8(7)]);
+        assert_eq!(
+            PrettyScript(&script).redacted().to_string(),
+            PrettyScript(&script).to_string()
+        );
+    }
+
+    #[test]
+    fn test_pretty_unknown_script() {
+        let script = Script::new(vec![0xca, 0xfe], vec![], vec![TransactionArgument::U8(7)]);
+        assert_eq!(
+            PrettyScript(&script).to_string(),
+            format!("<unknown {}>(7u8)", script_hash(&script))
+        );
+    }
+}
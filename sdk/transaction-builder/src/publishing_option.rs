@@ -0,0 +1,147 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Write sets updating the `DiemTransactionPublishingOption` on-chain config.
+//!
+//! The framework has no transaction script that sets the script allowlist, so the config is
+//! overwritten directly with a write set transaction sent by the Diem Root account. Three modes
+//! are supported, matching the constructors of `VMPublishingOption`:
+//! * `locked`: only the allowlisted scripts may be sent and modules may not be published,
+//! * `custom_scripts`: any script may be sent, but modules may not be published,
+//! * `open`: any script may be sent and modules may be published.
+//!
+//! Like other config changes made through a write set, the new option is only picked up by the
+//! VM at the next reconfiguration.
+
+use crate::{
+    builders::ChangeSetBuilder,
+    error::BuilderError,
+    stdlib_script::{stdlib_script_hash, StdlibScript},
+};
+use diem_crypto::HashValue;
+use diem_types::{
+    on_chain_config::{OnChainConfig, VMPublishingOption},
+    transaction::ChangeSet,
+    write_set::WriteOp,
+};
+
+/// Build the write set replacing the publishing option with `option`.
+pub fn encode_publishing_option_change_set(
+    option: &VMPublishingOption,
+) -> Result<ChangeSet, BuilderError> {
+    let bytes = bcs::to_bytes(option).map_err(|e| BuilderError::WriteSet(e.to_string()))?;
+    write_publishing_option(bytes)
+}
+
+/// Like [`encode_publishing_option_change_set`], but for an option that is already serialized,
+/// e.g. cached by a pipeline. The bytes are checked to deserialize into a `VMPublishingOption`.
+pub fn encode_publishing_option_change_set_from_bytes(
+    bytes: Vec<u8>,
+) -> Result<ChangeSet, BuilderError> {
+    VMPublishingOption::deserialize_into_config(&bytes).map_err(|e| {
+        BuilderError::InvalidArgument {
+            input: hex::encode(&bytes),
+            reason: e.to_string(),
+        }
+    })?;
+    write_publishing_option(bytes)
+}
+
+fn write_publishing_option(bytes: Vec<u8>) -> Result<ChangeSet, BuilderError> {
+    ChangeSetBuilder::new()
+        .push_write(
+            VMPublishingOption::CONFIG_ID.access_path(),
+            WriteOp::Value(bytes),
+        )
+        .build()
+}
+
+/// Build the write set restricting the scripts that may be sent to those with code hashing to
+/// one of `hashes`, see [`crate::stdlib_script::script_hash`].
+pub fn encode_allowlist_publishing_option(
+    hashes: Vec<HashValue>,
+) -> Result<ChangeSet, BuilderError> {
+    encode_publishing_option_change_set(&VMPublishingOption::locked(hashes))
+}
+
+/// Return the publishing option allowing `scripts` to be sent, and any module to be published if
+/// `is_open_module`, e.g. for [`encode_publishing_option_change_set`].
+///
+/// Note that an empty allowlist allows *every* script, so passing no scripts is the same as
+/// [`VMPublishingOption::custom_scripts`] or [`VMPublishingOption::open`].
+pub fn publishing_option_allowing(
+    scripts: &[StdlibScript],
+    is_open_module: bool,
+) -> VMPublishingOption {
+    let mut script_allow_list = vec![];
+    for hash in scripts.iter().copied().map(stdlib_script_hash) {
+        if !script_allow_list.contains(&hash) {
+            script_allow_list.push(hash);
+        }
+    }
+    VMPublishingOption {
+        script_allow_list,
+        is_open_module,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_allowlist_publishing_option() {
+        let hashes = vec![stdlib_script_hash(StdlibScript::PeerToPeerWithMetadata)];
+        let (write_set, events) = encode_allowlist_publishing_option(hashes.clone())
+            .unwrap()
+            .into_inner();
+        assert!(events.is_empty());
+        let writes: Vec<_> = write_set.iter().collect();
+        assert_eq!(writes.len(), 1);
+        let (access_path, op) = writes[0];
+        assert_eq!(access_path, &VMPublishingOption::CONFIG_ID.access_path());
+        match op {
+            WriteOp::Value(bytes) => assert_eq!(
+                VMPublishingOption::deserialize_into_config(bytes).unwrap(),
+                VMPublishingOption::locked(hashes)
+            ),
+            WriteOp::Deletion => panic!("Expected the publishing option to be written"),
+        }
+    }
+
+    #[test]
+    fn test_publishing_option_allowing() {
+        let option = publishing_option_allowing(
+            &[
+                StdlibScript::PeerToPeerWithMetadata,
+                StdlibScript::Preburn,
+                StdlibScript::PeerToPeerWithMetadata,
+            ],
+            false,
+        );
+        assert_eq!(
+            option,
+            VMPublishingOption::locked(vec![
+                stdlib_script_hash(StdlibScript::PeerToPeerWithMetadata),
+                stdlib_script_hash(StdlibScript::Preburn),
+            ])
+        );
+        assert_eq!(
+            publishing_option_allowing(&[], true),
+            VMPublishingOption::open()
+        );
+    }
+
+    #[test]
+    fn test_encode_publishing_option_change_set_from_bytes() {
+        let option = VMPublishingOption::open();
+        assert_eq!(
+            encode_publishing_option_change_set_from_bytes(bcs::to_bytes(&option).unwrap()),
+            encode_publishing_option_change_set(&option)
+        );
+        assert!(matches!(
+            encode_publishing_option_change_set_from_bytes(vec![0xff]),
+            Err(BuilderError::InvalidArgument { .. })
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encoding of scripts against a stdlib other than the one compiled into this crate.
+//!
+//! A network only runs scripts whose code is in its allowlist, so a transaction for a network
+//! still running an older stdlib must carry the code of that release. A [`ScriptEncoder`] loaded
+//! with the compiled scripts of the release builds such transactions. The arguments are laid out
+//! as for the compiled-in stdlib, so the signature of the scripts must be the same in both.
+
+use crate::{error::BuilderError, stdlib::ScriptCall, stdlib_script::StdlibScript};
+use diem_types::transaction::Script;
+use std::collections::BTreeMap;
+
+/// Where a [`ScriptEncoder`] takes the code of the scripts from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StdlibSource {
+    /// The scripts compiled into this crate, see [`StdlibScript::compiled_bytes`].
+    CompiledIn,
+    /// The code of each script, e.g. read from the release of the stdlib a network runs.
+    Loaded(BTreeMap<StdlibScript, Vec<u8>>),
+}
+
+impl StdlibSource {
+    /// Source holding the code of `scripts`. Scripts left out cannot be encoded.
+    pub fn from_bytes<I: IntoIterator<Item = (StdlibScript, Vec<u8>)>>(scripts: I) -> Self {
+        StdlibSource::Loaded(scripts.into_iter().collect())
+    }
+
+    /// Return the code of `script`, or `None` if this source does not have it.
+    pub fn code(&self, script: StdlibScript) -> Option<&[u8]> {
+        match self {
+            StdlibSource::CompiledIn => Some(script.compiled_bytes()),
+            StdlibSource::Loaded(scripts) => scripts.get(&script).map(Vec::as_slice),
+        }
+    }
+}
+
+impl Default for StdlibSource {
+    fn default() -> Self {
+        StdlibSource::CompiledIn
+    }
+}
+
+/// Encodes calls with the code of the scripts from a [`StdlibSource`], the compiled-in stdlib by
+/// default.
+#[derive(Clone, Debug, Default)]
+pub struct ScriptEncoder {
+    source: StdlibSource,
+}
+
+impl ScriptEncoder {
+    pub fn new(source: StdlibSource) -> Self {
+        Self { source }
+    }
+
+    pub fn source(&self) -> &StdlibSource {
+        &self.source
+    }
+
+    pub fn encode(&self, call: &ScriptCall) -> Result<Script, BuilderError> {
+        let script = call.encode();
+        let stdlib_script = StdlibScript::from_code(script.code())
+            .expect("Every ScriptCall encodes a compiled-in script");
+        let code = self
+            .source
+            .code(stdlib_script)
+            .ok_or(BuilderError::ScriptNotInSource {
+                name: stdlib_script.name(),
+            })?;
+        Ok(Script::new(
+            code.to_vec(),
+            script.ty_args().to_vec(),
+            script.args().to_vec(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::language_storage::TypeTag;
+
+    #[test]
+    fn test_script_encoder() {
+        let call = ScriptCall::Preburn {
+            token: TypeTag::Bool,
+            amount: 10,
+        };
+        assert_eq!(ScriptEncoder::default().encode(&call), Ok(call.encode()));
+
+        let encoder = ScriptEncoder::new(StdlibSource::from_bytes(vec![(
+            StdlibScript::Preburn,
+            vec![1, 2, 3],
+        )]));
+        assert_eq!(
+            encoder.encode(&call),
+            Ok(Script::new(
+                vec![1, 2, 3],
+                vec![TypeTag::Bool],
+                call.encode().args().to_vec(),
+            ))
+        );
+        assert_eq!(
+            encoder.encode(&ScriptCall::BurnTxnFees {
+                coin_type: TypeTag::Bool
+            }),
+            Err(BuilderError::ScriptNotInSource {
+                name: "burn_txn_fees"
+            })
+        );
+    }
+}
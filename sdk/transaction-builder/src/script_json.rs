@@ -0,0 +1,184 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Human-readable JSON rendering of scripts, for debugging and audit logs, and of the script
+//! catalog for tools generated from it.
+
+use crate::{
+    stdlib::ScriptCall,
+    stdlib_script::{argument_schemas, script_catalog, script_id, StdlibScript},
+};
+use diem_types::transaction::{Script, TransactionArgument};
+use serde_json::{json, Map, Value};
+use std::{fs, io, path::Path};
+
+/// The script name used when the code is not one of the known transaction scripts.
+pub const UNKNOWN_SCRIPT_NAME: &str = "<unknown>";
+
+/// Render a script as `{"script": .., "type_args": [..], "args": {..}}`, with the arguments keyed
+/// by their parameter names.
+///
+/// Scripts that fail to decode are rendered with `"script": "<unknown>"`, their code in hex and
+/// `args` as a list of hex-encoded BCS arguments, so that nothing is lost.
+pub fn script_to_json(script: &Script) -> Value {
+    let type_args: Vec<_> = script.ty_args().iter().map(ToString::to_string).collect();
+    match ScriptCall::decode(script).and_then(|call| StdlibScript::from_name(call.name())) {
+        Some(stdlib_script) => {
+            let args: Map<String, Value> = stdlib_script
+                .arg_names()
+                .iter()
+                .zip(script.args())
+                .map(|(name, arg)| (name.to_string(), argument_to_json(arg)))
+                .collect();
+            json!({
+                "script": stdlib_script.name(),
+                "type_args": type_args,
+                "args": args,
+            })
+        }
+        None => {
+            let args: Vec<_> = script
+                .args()
+                .iter()
+                .map(|arg| {
+                    hex::encode(bcs::to_bytes(arg).expect("TransactionArgument is serializable"))
+                })
+                .collect();
+            json!({
+                "script": UNKNOWN_SCRIPT_NAME,
+                "code": hex::encode(script.code()),
+                "type_args": type_args,
+                "args": args,
+            })
+        }
+    }
+}
+
+/// Render [`script_catalog`] and [`argument_schemas`] as pretty-printed JSON, e.g. for a
+/// `scripts.json` consumed by explorers and SDK generators. Scripts are listed in
+/// [`StdlibScript::all`] order and object keys are sorted, so the output only changes with the
+/// scripts themselves.
+pub fn catalog_json() -> String {
+    let scripts: Vec<_> = script_catalog()
+        .into_iter()
+        .zip(argument_schemas())
+        .map(|(info, schema)| {
+            let args: Vec<_> = info
+                .args
+                .iter()
+                .zip(&schema.args)
+                .map(|((name, move_type), (_, kind))| {
+                    json!({
+                        "name": name,
+                        "type": move_type,
+                        "kind": kind,
+                    })
+                })
+                .collect();
+            json!({
+                "name": info.name,
+                "id": script_id(info.script),
+                "summary": info.summary,
+                "type_args": info.type_args,
+                "args": args,
+            })
+        })
+        .collect();
+    let mut json = serde_json::to_string_pretty(&scripts).expect("Catalog is serializable");
+    json.push('\n');
+    json
+}
+
+/// Write [`catalog_json`] to `path`, e.g. from a build script. The file is left untouched if it
+/// is already up to date, so that its modification time only changes with its content.
+pub fn write_catalog_json(path: &Path) -> io::Result<()> {
+    let json = catalog_json();
+    if fs::read_to_string(path).map_or(false, |existing| existing == json) {
+        return Ok(());
+    }
+    fs::write(path, json)
+}
+
+/// `u128` values are rendered as strings since they do not fit in a JSON number.
+fn argument_to_json(arg: &TransactionArgument) -> Value {
+    match arg {
+        TransactionArgument::U8(value) => json!(value),
+        TransactionArgument::U64(value) => json!(value),
+        TransactionArgument::U128(value) => json!(value.to_string()),
+        TransactionArgument::Address(address) => json!(format!("0x{}", address.to_hex())),
+        TransactionArgument::U8Vector(bytes) => json!(hex::encode(bytes)),
+        TransactionArgument::AddressVector(addresses) => Value::Array(
+            addresses
+                .iter()
+                .map(|address| json!(format!("0x{}", address.to_hex())))
+                .collect(),
+        ),
+        TransactionArgument::Bool(value) => json!(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdlib;
+    use diem_types::account_address::AccountAddress;
+    use move_core_types::language_storage::TypeTag;
+
+    #[test]
+    fn test_known_script_to_json() {
+        let payee = AccountAddress::random();
+        let script = stdlib::encode_peer_to_peer_with_metadata_script(
+            TypeTag::Bool,
+            payee,
+            100,
+            vec![1, 2],
+            vec![],
+        );
+        assert_eq!(
+            script_to_json(&script),
+            json!({
+                "script": "peer_to_peer_with_metadata",
+                "type_args": ["Bool"],
+                "args": {
+                    "payee": format!("0x{}", payee.to_hex()),
+                    "amount": 100,
+                    "metadata": "0102",
+                    "metadata_signature": "",
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_catalog_json() {
+        let json = catalog_json();
+        assert_eq!(json, catalog_json());
+        let catalog: Value = serde_json::from_str(&json).unwrap();
+        let scripts = catalog.as_array().unwrap();
+        assert_eq!(scripts.len(), StdlibScript::all().len());
+        let preburn = scripts
+            .iter()
+            .find(|script| script["name"] == "preburn")
+            .unwrap();
+        assert_eq!(preburn["id"], json!(script_id(StdlibScript::Preburn)));
+        assert_eq!(preburn["type_args"], json!(["token"]));
+        assert_eq!(
+            preburn["args"],
+            json!([{"name": "amount", "type": "u64", "kind": "u64"}])
+        );
+    }
+
+    #[test]
+    fn test_unknown_script_to_json() {
+        let script = Script::new(vec![0xca, 0xfe], vec![], vec![TransactionArgument::U8(7)]);
+        assert_eq!(
+            script_to_json(&script),
+            json!({
+                "script": UNKNOWN_SCRIPT_NAME,
+                "code": "cafe",
+                "type_args": [],
+                "args": ["0007"],
+            })
+        );
+    }
+}
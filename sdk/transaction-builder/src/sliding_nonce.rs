@@ -0,0 +1,142 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Allocation of the `sliding_nonce` argument taken by the admin scripts.
+//!
+//! Scripts sent by the Diem Root and Treasury Compliance accounts, e.g. `freeze_account`, carry a
+//! sliding nonce instead of relying only on the sequence number of the sender, so that they can
+//! be executed out of order. The `SlidingNonce` module keeps a window of
+//! `SLIDING_NONCE_WINDOW_SIZE` nonces starting at a minimum nonce, and a script aborts if its
+//! nonce:
+//! * is below the window, i.e. too old to tell whether it was already used,
+//! * is `SLIDING_NONCE_JUMP_LIMIT` or more above the start of the window,
+//! * was already recorded.
+//!
+//! A nonce of 0 is never recorded, which disables the replay protection. Handing out increasing
+//! nonces from 1 therefore satisfies all of the above as long as fewer than
+//! `SLIDING_NONCE_WINDOW_SIZE` of them are in flight at once.
+//!
+//! The `try_*` encoders of [`crate::fallible`] take the nonce as a [`SlidingNonce`], so that it
+//! cannot be mixed up with the other `u64` arguments of the script.
+
+use crate::{error::BuilderError, fallible::validate_sliding_nonce};
+use std::ops::RangeInclusive;
+
+/// Number of nonces tracked by the window of a `SlidingNonce` resource.
+pub const SLIDING_NONCE_WINDOW_SIZE: u64 = 128;
+
+/// How far above the start of the window a nonce may be.
+pub const SLIDING_NONCE_JUMP_LIMIT: u64 = 10_000;
+
+/// The `sliding_nonce` argument of a script, which is never 0 as it can only be built with
+/// [`SlidingNonce::new`] or [`SlidingNonce::in_range`]. Code taking a `SlidingNonce` relies on
+/// this instead of checking the nonce again.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SlidingNonce(u64);
+
+impl SlidingNonce {
+    /// Wrap `nonce` after checking that it is not 0, see [`validate_sliding_nonce`].
+    pub fn new(nonce: u64) -> Result<Self, BuilderError> {
+        validate_sliding_nonce(nonce)?;
+        Ok(Self(nonce))
+    }
+
+    /// Wrap `nonce` after checking that it is not 0 and lies in `range`, e.g.
+    /// `min_nonce..=min_nonce + SLIDING_NONCE_JUMP_LIMIT - 1` where `min_nonce` is the start of
+    /// the window of the sender.
+    pub fn in_range(nonce: u64, range: RangeInclusive<u64>) -> Result<Self, BuilderError> {
+        let nonce = Self::new(nonce)?;
+        if !range.contains(&nonce.0) {
+            return Err(BuilderError::SlidingNonceOutOfRange {
+                nonce: nonce.0,
+                min: *range.start(),
+                max: *range.end(),
+            });
+        }
+        Ok(nonce)
+    }
+
+    pub fn get(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<SlidingNonce> for u64 {
+    fn from(nonce: SlidingNonce) -> Self {
+        nonce.0
+    }
+}
+
+/// Hands out increasing sliding nonces for one sending account, never returning 0.
+#[derive(Clone, Debug)]
+pub struct NonceAllocator {
+    next: u64,
+}
+
+impl NonceAllocator {
+    /// Start allocating after `last_used`, the highest nonce already sent by the account, or 0
+    /// if it never sent one.
+    pub fn new(last_used: u64) -> Self {
+        Self {
+            next: last_used.saturating_add(1),
+        }
+    }
+
+    /// Return the next unused nonce.
+    pub fn next_nonce(&mut self) -> SlidingNonce {
+        let nonce = SlidingNonce::new(self.next).expect("Allocated nonces start at 1");
+        self.next = self.next.saturating_add(1);
+        nonce
+    }
+}
+
+impl Default for NonceAllocator {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nonce_allocator() {
+        let mut allocator = NonceAllocator::default();
+        assert_eq!(allocator.next_nonce().get(), 1);
+        assert_eq!(allocator.next_nonce().get(), 2);
+        let mut allocator = NonceAllocator::new(41);
+        let nonce = allocator.next_nonce();
+        assert_eq!(nonce.get(), 42);
+        assert_eq!(validate_sliding_nonce(nonce.get()), Ok(()));
+    }
+
+    #[test]
+    fn test_sliding_nonce() {
+        assert_eq!(SlidingNonce::new(1), Ok(SlidingNonce(1)));
+        assert_eq!(SlidingNonce::new(0), Err(BuilderError::ZeroSlidingNonce));
+        assert_eq!(u64::from(SlidingNonce(7)), 7);
+
+        let window = 100..=100 + SLIDING_NONCE_JUMP_LIMIT - 1;
+        assert_eq!(
+            SlidingNonce::in_range(100, window.clone()),
+            Ok(SlidingNonce(100))
+        );
+        assert_eq!(
+            SlidingNonce::in_range(99 + SLIDING_NONCE_JUMP_LIMIT, window.clone()),
+            Ok(SlidingNonce(99 + SLIDING_NONCE_JUMP_LIMIT))
+        );
+        assert_eq!(
+            SlidingNonce::in_range(99, window.clone()),
+            Err(BuilderError::SlidingNonceOutOfRange {
+                nonce: 99,
+                min: 100,
+                max: 99 + SLIDING_NONCE_JUMP_LIMIT,
+            })
+        );
+        assert_eq!(
+            SlidingNonce::in_range(0, 0..=10),
+            Err(BuilderError::ZeroSlidingNonce)
+        );
+    }
+}
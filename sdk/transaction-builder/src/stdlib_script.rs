@@ -0,0 +1,1936 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Untyped access to the transaction scripts that have a generated builder.
+
+use crate::{error::BuilderError, fallible, sliding_nonce::SlidingNonce, stdlib};
+use diem_crypto::HashValue;
+use diem_types::{
+    account_address::AccountAddress,
+    transaction::{Script, TransactionArgument, TransactionPayload},
+};
+use move_core_types::language_storage::TypeTag;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// All of the Move transaction scripts that have an encoder in [`crate::stdlib`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum StdlibScript {
+    AddCurrencyToAccount,
+    AddRecoveryRotationCapability,
+    AddValidatorAndReconfigure,
+    Burn,
+    BurnTxnFees,
+    CancelBurn,
+    CreateChildVaspAccount,
+    CreateDesignatedDealer,
+    CreateParentVaspAccount,
+    CreateRecoveryAddress,
+    CreateValidatorAccount,
+    CreateValidatorOperatorAccount,
+    FreezeAccount,
+    PeerToPeerWithMetadata,
+    Preburn,
+    PublishSharedEd25519PublicKey,
+    RegisterValidatorConfig,
+    RemoveValidatorAndReconfigure,
+    RotateAuthenticationKey,
+    RotateAuthenticationKeyWithNonce,
+    RotateAuthenticationKeyWithNonceAdmin,
+    RotateAuthenticationKeyWithRecoveryAddress,
+    RotateDualAttestationInfo,
+    RotateSharedEd25519PublicKey,
+    SetValidatorConfigAndReconfigure,
+    SetValidatorOperator,
+    SetValidatorOperatorWithNonceAdmin,
+    TieredMint,
+    UnfreezeAccount,
+    UpdateDiemVersion,
+    UpdateDualAttestationLimit,
+    UpdateExchangeRate,
+    UpdateMintingAbility,
+}
+
+/// Reverse index of [`StdlibScript::compiled_bytes`].
+static SCRIPTS_BY_CODE: Lazy<HashMap<&'static [u8], StdlibScript>> = Lazy::new(|| {
+    StdlibScript::all()
+        .iter()
+        .map(|script| (script.compiled_bytes(), *script))
+        .collect()
+});
+
+impl StdlibScript {
+    /// Return all of the scripts (i.e., all inhabitants of the `StdlibScript` enum).
+    pub fn all() -> &'static [StdlibScript] {
+        use StdlibScript::*;
+        &[
+            AddCurrencyToAccount,
+            AddRecoveryRotationCapability,
+            AddValidatorAndReconfigure,
+            Burn,
+            BurnTxnFees,
+            CancelBurn,
+            CreateChildVaspAccount,
+            CreateDesignatedDealer,
+            CreateParentVaspAccount,
+            CreateRecoveryAddress,
+            CreateValidatorAccount,
+            CreateValidatorOperatorAccount,
+            FreezeAccount,
+            PeerToPeerWithMetadata,
+            Preburn,
+            PublishSharedEd25519PublicKey,
+            RegisterValidatorConfig,
+            RemoveValidatorAndReconfigure,
+            RotateAuthenticationKey,
+            RotateAuthenticationKeyWithNonce,
+            RotateAuthenticationKeyWithNonceAdmin,
+            RotateAuthenticationKeyWithRecoveryAddress,
+            RotateDualAttestationInfo,
+            RotateSharedEd25519PublicKey,
+            SetValidatorConfigAndReconfigure,
+            SetValidatorOperator,
+            SetValidatorOperatorWithNonceAdmin,
+            TieredMint,
+            UnfreezeAccount,
+            UpdateDiemVersion,
+            UpdateDualAttestationLimit,
+            UpdateExchangeRate,
+            UpdateMintingAbility,
+        ]
+    }
+
+    /// Look up a script by its Move bytecode.
+    pub fn from_code(code: &[u8]) -> Option<Self> {
+        SCRIPTS_BY_CODE.get(code).copied()
+    }
+
+    /// Look up a script by its lowercase-underscore style name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|script| script.name() == name)
+    }
+
+    /// Return the lowercase-underscore style name of this script.
+    pub fn name(self) -> &'static str {
+        use StdlibScript::*;
+        match self {
+            AddCurrencyToAccount => "add_currency_to_account",
+            AddRecoveryRotationCapability => "add_recovery_rotation_capability",
+            AddValidatorAndReconfigure => "add_validator_and_reconfigure",
+            Burn => "burn",
+            BurnTxnFees => "burn_txn_fees",
+            CancelBurn => "cancel_burn",
+            CreateChildVaspAccount => "create_child_vasp_account",
+            CreateDesignatedDealer => "create_designated_dealer",
+            CreateParentVaspAccount => "create_parent_vasp_account",
+            CreateRecoveryAddress => "create_recovery_address",
+            CreateValidatorAccount => "create_validator_account",
+            CreateValidatorOperatorAccount => "create_validator_operator_account",
+            FreezeAccount => "freeze_account",
+            PeerToPeerWithMetadata => "peer_to_peer_with_metadata",
+            Preburn => "preburn",
+            PublishSharedEd25519PublicKey => "publish_shared_ed25519_public_key",
+            RegisterValidatorConfig => "register_validator_config",
+            RemoveValidatorAndReconfigure => "remove_validator_and_reconfigure",
+            RotateAuthenticationKey => "rotate_authentication_key",
+            RotateAuthenticationKeyWithNonce => "rotate_authentication_key_with_nonce",
+            RotateAuthenticationKeyWithNonceAdmin => "rotate_authentication_key_with_nonce_admin",
+            RotateAuthenticationKeyWithRecoveryAddress => {
+                "rotate_authentication_key_with_recovery_address"
+            }
+            RotateDualAttestationInfo => "rotate_dual_attestation_info",
+            RotateSharedEd25519PublicKey => "rotate_shared_ed25519_public_key",
+            SetValidatorConfigAndReconfigure => "set_validator_config_and_reconfigure",
+            SetValidatorOperator => "set_validator_operator",
+            SetValidatorOperatorWithNonceAdmin => "set_validator_operator_with_nonce_admin",
+            TieredMint => "tiered_mint",
+            UnfreezeAccount => "unfreeze_account",
+            UpdateDiemVersion => "update_diem_version",
+            UpdateDualAttestationLimit => "update_dual_attestation_limit",
+            UpdateExchangeRate => "update_exchange_rate",
+            UpdateMintingAbility => "update_minting_ability",
+        }
+    }
+
+    /// Return the summary from the documentation of this script.
+    pub fn summary(self) -> &'static str {
+        use StdlibScript::*;
+        match self {
+            AddCurrencyToAccount => {
+                "Adds a zero `Currency` balance to the sending `account`. This will enable \
+                 `account` to send, receive, and hold `Diem::Diem<Currency>` coins. This \
+                 transaction can be successfully sent by any account that is allowed to hold \
+                 balances (e.g., VASP, Designated Dealer)."
+            }
+            AddRecoveryRotationCapability => {
+                "Stores the sending accounts ability to rotate its authentication key with a \
+                 designated recovery account. Both the sending and recovery accounts need to \
+                 belong to the same VASP and both be VASP accounts. After this transaction both \
+                 the sending account and the specified recovery account can rotate the sender \
+                 account's authentication key."
+            }
+            AddValidatorAndReconfigure => {
+                "Adds a validator account to the validator set, and triggers a reconfiguration of \
+                 the system to admit the account to the validator set for the system. This \
+                 transaction can only be successfully called by the Diem Root account."
+            }
+            Burn => {
+                "Burns all coins held in the preburn resource at the specified preburn address \
+                 and removes them from the system. The sending account must be the Treasury \
+                 Compliance account. The account that holds the preburn resource will normally be \
+                 a Designated Dealer, but there are no enforced requirements that it be one."
+            }
+            BurnTxnFees => {
+                "Burns the transaction fees collected in the `CoinType` currency so that the Diem \
+                 association may reclaim the backing coins off-chain. May only be sent by the \
+                 Treasury Compliance account."
+            }
+            CancelBurn => {
+                "Cancels and returns all coins held in the preburn area under `preburn_address` \
+                 and returns the funds to the `preburn_address`'s balance. Can only be \
+                 successfully sent by an account with Treasury Compliance role."
+            }
+            CreateChildVaspAccount => {
+                "Creates a Child VASP account with its parent being the sending account of the \
+                 transaction. The sender of the transaction must be a Parent VASP account."
+            }
+            CreateDesignatedDealer => {
+                "Creates a Designated Dealer account with the provided information, and \
+                 initializes it with default mint tiers. The transaction can only be sent by the \
+                 Treasury Compliance account."
+            }
+            CreateParentVaspAccount => {
+                "Creates a Parent VASP account with the specified human name. Must be called by \
+                 the Treasury Compliance account."
+            }
+            CreateRecoveryAddress => {
+                "Initializes the sending account as a recovery address that may be used by the \
+                 VASP that it belongs to. The sending account must be a VASP account. Multiple \
+                 recovery addresses can exist for a single VASP, but accounts in each must be \
+                 disjoint."
+            }
+            CreateValidatorAccount => {
+                "Creates a Validator account. This transaction can only be sent by the Diem Root \
+                 account."
+            }
+            CreateValidatorOperatorAccount => {
+                "Creates a Validator Operator account. This transaction can only be sent by the \
+                 Diem Root account."
+            }
+            FreezeAccount => {
+                "Freezes the account at `address`. The sending account of this transaction must \
+                 be the Treasury Compliance account. The account being frozen cannot be the Diem \
+                 Root or Treasury Compliance account. After the successful execution of this \
+                 transaction no transactions may be sent from the frozen account, and the frozen \
+                 account may not send or receive coins."
+            }
+            PeerToPeerWithMetadata => {
+                "Transfers a given number of coins in a specified currency from one account to \
+                 another. Transfers over a specified amount defined on-chain that are between two \
+                 different VASPs, or other accounts that have opted-in will be subject to \
+                 on-chain checks to ensure the receiver has agreed to receive the coins. This \
+                 transaction can be sent by any account that can hold a balance, and to any \
+                 account that can hold a balance. Both accounts must hold balances in the \
+                 currency being transacted."
+            }
+            Preburn => {
+                "Moves a specified number of coins in a given currency from the account's balance \
+                 to its preburn area after which the coins may be burned. This transaction may be \
+                 sent by any account that holds a balance and preburn area in the specified \
+                 currency."
+            }
+            PublishSharedEd25519PublicKey => {
+                "Rotates the authentication key of the sending account to the newly-specified \
+                 public key and publishes a new shared authentication key under the sender's \
+                 account. Any account can send this transaction."
+            }
+            RegisterValidatorConfig => {
+                "Updates a validator's configuration. This does not reconfigure the system and \
+                 will not update the configuration in the validator set that is seen by other \
+                 validators in the network. Can only be successfully sent by a Validator Operator \
+                 account that is already registered with a validator."
+            }
+            RemoveValidatorAndReconfigure => {
+                "This script removes a validator account from the validator set, and triggers a \
+                 reconfiguration of the system to remove the validator from the system. This \
+                 transaction can only be successfully called by the Diem Root account."
+            }
+            RotateAuthenticationKey => {
+                "Rotates the transaction sender's authentication key to the supplied new \
+                 authentication key. May be sent by any account."
+            }
+            RotateAuthenticationKeyWithNonce => {
+                "Rotates the sender's authentication key to the supplied new authentication key. \
+                 May be sent by any account that has a sliding nonce resource published under it \
+                 (usually this is Treasury Compliance or Diem Root accounts)."
+            }
+            RotateAuthenticationKeyWithNonceAdmin => {
+                "Rotates the specified account's authentication key to the supplied new \
+                 authentication key. May only be sent by the Diem Root account as a write set \
+                 transaction."
+            }
+            RotateAuthenticationKeyWithRecoveryAddress => {
+                "Rotates the authentication key of a specified account that is part of a recovery \
+                 address to a new authentication key. Only used for accounts that are part of a \
+                 recovery address (see `Script::add_recovery_rotation_capability` for account \
+                 restrictions)."
+            }
+            RotateDualAttestationInfo => {
+                "Updates the url used for off-chain communication, and the public key used to \
+                 verify dual attestation on-chain. Transaction can be sent by any account that \
+                 has dual attestation information published under it. In practice the only such \
+                 accounts are Designated Dealers and Parent VASPs."
+            }
+            RotateSharedEd25519PublicKey => {
+                "Rotates the authentication key in a `SharedEd25519PublicKey`. This transaction \
+                 can be sent by any account that has previously published a shared ed25519 public \
+                 key using `Script::publish_shared_ed25519_public_key`."
+            }
+            SetValidatorConfigAndReconfigure => {
+                "Updates a validator's configuration, and triggers a reconfiguration of the \
+                 system to update the validator set with this new validator configuration. Can \
+                 only be successfully sent by a Validator Operator account that is already \
+                 registered with a validator."
+            }
+            SetValidatorOperator => {
+                "Sets the validator operator for a validator in the validator's configuration \
+                 resource \"locally\" and does not reconfigure the system. Changes from this \
+                 transaction will not picked up by the system until a reconfiguration of the \
+                 system is triggered. May only be sent by an account with Validator role."
+            }
+            SetValidatorOperatorWithNonceAdmin => {
+                "Sets the validator operator for a validator in the validator's configuration \
+                 resource \"locally\" and does not reconfigure the system. Changes from this \
+                 transaction will not picked up by the system until a reconfiguration of the \
+                 system is triggered. May only be sent by the Diem Root account as a write set \
+                 transaction."
+            }
+            TieredMint => {
+                "Mints a specified number of coins in a currency to a Designated Dealer. The \
+                 sending account must be the Treasury Compliance account, and coins can only be \
+                 minted to a Designated Dealer account."
+            }
+            UnfreezeAccount => {
+                "Unfreezes the account at `address`. The sending account of this transaction must \
+                 be the Treasury Compliance account. After the successful execution of this \
+                 transaction transactions may be sent from the previously frozen account, and \
+                 coins may be sent and received."
+            }
+            UpdateDiemVersion => {
+                "Updates the Diem major version that is stored on-chain and is used by the VM. \
+                 This transaction can only be sent from the Diem Root account."
+            }
+            UpdateDualAttestationLimit => {
+                "Update the dual attestation limit on-chain. Defined in terms of micro-XDX. The \
+                 transaction can only be sent by the Treasury Compliance account. After this \
+                 transaction all inter-VASP payments over this limit must be checked for dual \
+                 attestation."
+            }
+            UpdateExchangeRate => {
+                "Update the rough on-chain exchange rate between a specified currency and XDX (as \
+                 a conversion to micro-XDX). The transaction can only be sent by the Treasury \
+                 Compliance account. After this transaction the updated exchange rate will be \
+                 used for normalization of gas prices, and for dual attestation checking."
+            }
+            UpdateMintingAbility => {
+                "Script to allow or disallow minting of new coins in a specified currency. This \
+                 transaction can only be sent by the Treasury Compliance account. Turning minting \
+                 off for a currency will have no effect on coins already in circulation, and \
+                 coins may still be removed from the system."
+            }
+        }
+    }
+
+    /// Return the names of the type parameters of this script, in order.
+    pub fn type_arg_names(self) -> &'static [&'static str] {
+        use StdlibScript::*;
+        match self {
+            AddCurrencyToAccount => &["currency"],
+            AddRecoveryRotationCapability => &[],
+            AddValidatorAndReconfigure => &[],
+            Burn => &["token"],
+            BurnTxnFees => &["coin_type"],
+            CancelBurn => &["token"],
+            CreateChildVaspAccount => &["coin_type"],
+            CreateDesignatedDealer => &["currency"],
+            CreateParentVaspAccount => &["coin_type"],
+            CreateRecoveryAddress => &[],
+            CreateValidatorAccount => &[],
+            CreateValidatorOperatorAccount => &[],
+            FreezeAccount => &[],
+            PeerToPeerWithMetadata => &["currency"],
+            Preburn => &["token"],
+            PublishSharedEd25519PublicKey => &[],
+            RegisterValidatorConfig => &[],
+            RemoveValidatorAndReconfigure => &[],
+            RotateAuthenticationKey => &[],
+            RotateAuthenticationKeyWithNonce => &[],
+            RotateAuthenticationKeyWithNonceAdmin => &[],
+            RotateAuthenticationKeyWithRecoveryAddress => &[],
+            RotateDualAttestationInfo => &[],
+            RotateSharedEd25519PublicKey => &[],
+            SetValidatorConfigAndReconfigure => &[],
+            SetValidatorOperator => &[],
+            SetValidatorOperatorWithNonceAdmin => &[],
+            TieredMint => &["coin_type"],
+            UnfreezeAccount => &[],
+            UpdateDiemVersion => &[],
+            UpdateDualAttestationLimit => &[],
+            UpdateExchangeRate => &["currency"],
+            UpdateMintingAbility => &["currency"],
+        }
+    }
+
+    /// Return the names of the arguments of this script, in order.
+    pub fn arg_names(self) -> &'static [&'static str] {
+        use StdlibScript::*;
+        match self {
+            AddCurrencyToAccount => &[],
+            AddRecoveryRotationCapability => &["recovery_address"],
+            AddValidatorAndReconfigure => &["sliding_nonce", "validator_name", "validator_address"],
+            Burn => &["sliding_nonce", "preburn_address"],
+            BurnTxnFees => &[],
+            CancelBurn => &["preburn_address"],
+            CreateChildVaspAccount => &[
+                "child_address",
+                "auth_key_prefix",
+                "add_all_currencies",
+                "child_initial_balance",
+            ],
+            CreateDesignatedDealer => &[
+                "sliding_nonce",
+                "addr",
+                "auth_key_prefix",
+                "human_name",
+                "add_all_currencies",
+            ],
+            CreateParentVaspAccount => &[
+                "sliding_nonce",
+                "new_account_address",
+                "auth_key_prefix",
+                "human_name",
+                "add_all_currencies",
+            ],
+            CreateRecoveryAddress => &[],
+            CreateValidatorAccount => &[
+                "sliding_nonce",
+                "new_account_address",
+                "auth_key_prefix",
+                "human_name",
+            ],
+            CreateValidatorOperatorAccount => &[
+                "sliding_nonce",
+                "new_account_address",
+                "auth_key_prefix",
+                "human_name",
+            ],
+            FreezeAccount => &["sliding_nonce", "to_freeze_account"],
+            PeerToPeerWithMetadata => &["payee", "amount", "metadata", "metadata_signature"],
+            Preburn => &["amount"],
+            PublishSharedEd25519PublicKey => &["public_key"],
+            RegisterValidatorConfig => &[
+                "validator_account",
+                "consensus_pubkey",
+                "validator_network_addresses",
+                "fullnode_network_addresses",
+            ],
+            RemoveValidatorAndReconfigure => {
+                &["sliding_nonce", "validator_name", "validator_address"]
+            }
+            RotateAuthenticationKey => &["new_key"],
+            RotateAuthenticationKeyWithNonce => &["sliding_nonce", "new_key"],
+            RotateAuthenticationKeyWithNonceAdmin => &["sliding_nonce", "new_key"],
+            RotateAuthenticationKeyWithRecoveryAddress => {
+                &["recovery_address", "to_recover", "new_key"]
+            }
+            RotateDualAttestationInfo => &["new_url", "new_key"],
+            RotateSharedEd25519PublicKey => &["public_key"],
+            SetValidatorConfigAndReconfigure => &[
+                "validator_account",
+                "consensus_pubkey",
+                "validator_network_addresses",
+                "fullnode_network_addresses",
+            ],
+            SetValidatorOperator => &["operator_name", "operator_account"],
+            SetValidatorOperatorWithNonceAdmin => {
+                &["sliding_nonce", "operator_name", "operator_account"]
+            }
+            TieredMint => &[
+                "sliding_nonce",
+                "designated_dealer_address",
+                "mint_amount",
+                "tier_index",
+            ],
+            UnfreezeAccount => &["sliding_nonce", "to_unfreeze_account"],
+            UpdateDiemVersion => &["sliding_nonce", "major"],
+            UpdateDualAttestationLimit => &["sliding_nonce", "new_micro_xdx_limit"],
+            UpdateExchangeRate => &[
+                "sliding_nonce",
+                "new_exchange_rate_numerator",
+                "new_exchange_rate_denominator",
+            ],
+            UpdateMintingAbility => &["allow_minting"],
+        }
+    }
+
+    /// Return the Move bytecode of this script, as embedded in [`crate::stdlib`].
+    pub fn compiled_bytes(self) -> &'static [u8] {
+        use StdlibScript::*;
+        match self {
+            AddCurrencyToAccount => stdlib::ADD_CURRENCY_TO_ACCOUNT_CODE,
+            AddRecoveryRotationCapability => stdlib::ADD_RECOVERY_ROTATION_CAPABILITY_CODE,
+            AddValidatorAndReconfigure => stdlib::ADD_VALIDATOR_AND_RECONFIGURE_CODE,
+            Burn => stdlib::BURN_CODE,
+            BurnTxnFees => stdlib::BURN_TXN_FEES_CODE,
+            CancelBurn => stdlib::CANCEL_BURN_CODE,
+            CreateChildVaspAccount => stdlib::CREATE_CHILD_VASP_ACCOUNT_CODE,
+            CreateDesignatedDealer => stdlib::CREATE_DESIGNATED_DEALER_CODE,
+            CreateParentVaspAccount => stdlib::CREATE_PARENT_VASP_ACCOUNT_CODE,
+            CreateRecoveryAddress => stdlib::CREATE_RECOVERY_ADDRESS_CODE,
+            CreateValidatorAccount => stdlib::CREATE_VALIDATOR_ACCOUNT_CODE,
+            CreateValidatorOperatorAccount => stdlib::CREATE_VALIDATOR_OPERATOR_ACCOUNT_CODE,
+            FreezeAccount => stdlib::FREEZE_ACCOUNT_CODE,
+            PeerToPeerWithMetadata => stdlib::PEER_TO_PEER_WITH_METADATA_CODE,
+            Preburn => stdlib::PREBURN_CODE,
+            PublishSharedEd25519PublicKey => stdlib::PUBLISH_SHARED_ED25519_PUBLIC_KEY_CODE,
+            RegisterValidatorConfig => stdlib::REGISTER_VALIDATOR_CONFIG_CODE,
+            RemoveValidatorAndReconfigure => stdlib::REMOVE_VALIDATOR_AND_RECONFIGURE_CODE,
+            RotateAuthenticationKey => stdlib::ROTATE_AUTHENTICATION_KEY_CODE,
+            RotateAuthenticationKeyWithNonce => stdlib::ROTATE_AUTHENTICATION_KEY_WITH_NONCE_CODE,
+            RotateAuthenticationKeyWithNonceAdmin => {
+                stdlib::ROTATE_AUTHENTICATION_KEY_WITH_NONCE_ADMIN_CODE
+            }
+            RotateDualAttestationInfo => stdlib::ROTATE_DUAL_ATTESTATION_INFO_CODE,
+            RotateSharedEd25519PublicKey => stdlib::ROTATE_SHARED_ED25519_PUBLIC_KEY_CODE,
+            SetValidatorConfigAndReconfigure => stdlib::SET_VALIDATOR_CONFIG_AND_RECONFIGURE_CODE,
+            SetValidatorOperator => stdlib::SET_VALIDATOR_OPERATOR_CODE,
+            SetValidatorOperatorWithNonceAdmin => {
+                stdlib::SET_VALIDATOR_OPERATOR_WITH_NONCE_ADMIN_CODE
+            }
+            TieredMint => stdlib::TIERED_MINT_CODE,
+            UnfreezeAccount => stdlib::UNFREEZE_ACCOUNT_CODE,
+            UpdateDiemVersion => stdlib::UPDATE_DIEM_VERSION_CODE,
+            UpdateDualAttestationLimit => stdlib::UPDATE_DUAL_ATTESTATION_LIMIT_CODE,
+            UpdateExchangeRate => stdlib::UPDATE_EXCHANGE_RATE_CODE,
+            UpdateMintingAbility => stdlib::UPDATE_MINTING_ABILITY_CODE,
+        }
+    }
+
+    /// A call to this script with placeholder values, from which the number and types of its
+    /// arguments are recovered. The match is exhaustive, so adding a variant without an encoder
+    /// fails to compile.
+    pub(crate) fn placeholder_script(self) -> Script {
+        use StdlibScript::*;
+        match self {
+            AddCurrencyToAccount => stdlib::encode_add_currency_to_account_script(TypeTag::Bool),
+            AddRecoveryRotationCapability => {
+                stdlib::encode_add_recovery_rotation_capability_script(AccountAddress::ZERO)
+            }
+            AddValidatorAndReconfigure => {
+                stdlib::encode_add_validator_and_reconfigure_script(0, vec![], AccountAddress::ZERO)
+            }
+            Burn => stdlib::encode_burn_script(TypeTag::Bool, 0, AccountAddress::ZERO),
+            BurnTxnFees => stdlib::encode_burn_txn_fees_script(TypeTag::Bool),
+            CancelBurn => stdlib::encode_cancel_burn_script(TypeTag::Bool, AccountAddress::ZERO),
+            CreateChildVaspAccount => stdlib::encode_create_child_vasp_account_script(
+                TypeTag::Bool,
+                AccountAddress::ZERO,
+                vec![],
+                false,
+                0,
+            ),
+            CreateDesignatedDealer => stdlib::encode_create_designated_dealer_script(
+                TypeTag::Bool,
+                0,
+                AccountAddress::ZERO,
+                vec![],
+                vec![],
+                false,
+            ),
+            CreateParentVaspAccount => stdlib::encode_create_parent_vasp_account_script(
+                TypeTag::Bool,
+                0,
+                AccountAddress::ZERO,
+                vec![],
+                vec![],
+                false,
+            ),
+            CreateRecoveryAddress => stdlib::encode_create_recovery_address_script(),
+            CreateValidatorAccount => stdlib::encode_create_validator_account_script(
+                0,
+                AccountAddress::ZERO,
+                vec![],
+                vec![],
+            ),
+            CreateValidatorOperatorAccount => {
+                stdlib::encode_create_validator_operator_account_script(
+                    0,
+                    AccountAddress::ZERO,
+                    vec![],
+                    vec![],
+                )
+            }
+            FreezeAccount => stdlib::encode_freeze_account_script(0, AccountAddress::ZERO),
+            PeerToPeerWithMetadata => stdlib::encode_peer_to_peer_with_metadata_script(
+                TypeTag::Bool,
+                AccountAddress::ZERO,
+                0,
+                vec![],
+                vec![],
+            ),
+            Preburn => stdlib::encode_preburn_script(TypeTag::Bool, 0),
+            PublishSharedEd25519PublicKey => {
+                stdlib::encode_publish_shared_ed25519_public_key_script(vec![])
+            }
+            RegisterValidatorConfig => stdlib::encode_register_validator_config_script(
+                AccountAddress::ZERO,
+                vec![],
+                vec![],
+                vec![],
+            ),
+            RemoveValidatorAndReconfigure => {
+                stdlib::encode_remove_validator_and_reconfigure_script(
+                    0,
+                    vec![],
+                    AccountAddress::ZERO,
+                )
+            }
+            RotateAuthenticationKey => stdlib::encode_rotate_authentication_key_script(vec![]),
+            RotateAuthenticationKeyWithNonce => {
+                stdlib::encode_rotate_authentication_key_with_nonce_script(0, vec![])
+            }
+            RotateAuthenticationKeyWithNonceAdmin => {
+                stdlib::encode_rotate_authentication_key_with_nonce_admin_script(0, vec![])
+            }
+            RotateAuthenticationKeyWithRecoveryAddress => {
+                stdlib::encode_rotate_authentication_key_with_recovery_address_script(
+                    AccountAddress::ZERO,
+                    AccountAddress::ZERO,
+                    vec![],
+                )
+            }
+            RotateDualAttestationInfo => {
+                stdlib::encode_rotate_dual_attestation_info_script(vec![], vec![])
+            }
+            RotateSharedEd25519PublicKey => {
+                stdlib::encode_rotate_shared_ed25519_public_key_script(vec![])
+            }
+            SetValidatorConfigAndReconfigure => {
+                stdlib::encode_set_validator_config_and_reconfigure_script(
+                    AccountAddress::ZERO,
+                    vec![],
+                    vec![],
+                    vec![],
+                )
+            }
+            SetValidatorOperator => {
+                stdlib::encode_set_validator_operator_script(vec![], AccountAddress::ZERO)
+            }
+            SetValidatorOperatorWithNonceAdmin => {
+                stdlib::encode_set_validator_operator_with_nonce_admin_script(
+                    0,
+                    vec![],
+                    AccountAddress::ZERO,
+                )
+            }
+            TieredMint => {
+                stdlib::encode_tiered_mint_script(TypeTag::Bool, 0, AccountAddress::ZERO, 0, 0)
+            }
+            UnfreezeAccount => stdlib::encode_unfreeze_account_script(0, AccountAddress::ZERO),
+            UpdateDiemVersion => stdlib::encode_update_diem_version_script(0, 0),
+            UpdateDualAttestationLimit => stdlib::encode_update_dual_attestation_limit_script(0, 0),
+            UpdateExchangeRate => {
+                stdlib::encode_update_exchange_rate_script(TypeTag::Bool, 0, 0, 0)
+            }
+            UpdateMintingAbility => {
+                stdlib::encode_update_minting_ability_script(TypeTag::Bool, false)
+            }
+        }
+    }
+}
+
+/// Return the stable numeric id of `script`, e.g. to bucket transactions in metrics.
+///
+/// The mapping is append-only: a new script gets the next unused id and the id of a removed
+/// script is never reused, so that ids recorded in the past keep their meaning.
+pub fn script_id(script: StdlibScript) -> u16 {
+    use StdlibScript::*;
+    match script {
+        AddCurrencyToAccount => 0,
+        AddRecoveryRotationCapability => 1,
+        AddValidatorAndReconfigure => 2,
+        Burn => 3,
+        BurnTxnFees => 4,
+        CancelBurn => 5,
+        CreateChildVaspAccount => 6,
+        CreateDesignatedDealer => 7,
+        CreateParentVaspAccount => 8,
+        CreateRecoveryAddress => 9,
+        CreateValidatorAccount => 10,
+        CreateValidatorOperatorAccount => 11,
+        FreezeAccount => 12,
+        PeerToPeerWithMetadata => 13,
+        Preburn => 14,
+        PublishSharedEd25519PublicKey => 15,
+        RegisterValidatorConfig => 16,
+        RemoveValidatorAndReconfigure => 17,
+        RotateAuthenticationKey => 18,
+        RotateAuthenticationKeyWithNonce => 19,
+        RotateAuthenticationKeyWithNonceAdmin => 20,
+        RotateAuthenticationKeyWithRecoveryAddress => 21,
+        RotateDualAttestationInfo => 22,
+        RotateSharedEd25519PublicKey => 23,
+        SetValidatorConfigAndReconfigure => 24,
+        SetValidatorOperator => 25,
+        SetValidatorOperatorWithNonceAdmin => 26,
+        TieredMint => 27,
+        UnfreezeAccount => 28,
+        UpdateDiemVersion => 29,
+        UpdateDualAttestationLimit => 30,
+        UpdateExchangeRate => 31,
+        UpdateMintingAbility => 32,
+    }
+}
+
+/// Return the script with numeric id `id`, see [`script_id`].
+pub fn script_from_id(id: u16) -> Option<StdlibScript> {
+    StdlibScript::all()
+        .iter()
+        .copied()
+        .find(|script| script_id(*script) == id)
+}
+
+/// Return the script of the compiled-in stdlib whose code is exactly the code of `script`, or
+/// `None` for a script built against another stdlib or tampered with.
+pub fn stdlib_script_of(script: &Script) -> Option<StdlibScript> {
+    StdlibScript::from_code(script.code())
+}
+
+/// Check that the code of `script` is that of a script of the compiled-in stdlib, e.g. to flag a
+/// stale or tampered script before it is submitted.
+pub fn is_current_stdlib(script: &Script) -> bool {
+    stdlib_script_of(script).is_some()
+}
+
+/// Return the hash under which `script` appears in the script allowlist of `VMPublishingOption`,
+/// i.e. the SHA3-256 hash of its code.
+pub fn script_hash(script: &Script) -> HashValue {
+    HashValue::sha3_256_of(script.code())
+}
+
+/// Return the allowlist hash of `script`, see [`script_hash`].
+pub fn stdlib_script_hash(script: StdlibScript) -> HashValue {
+    HashValue::sha3_256_of(script.compiled_bytes())
+}
+
+/// Return the allowlist hash of every script in [`StdlibScript::all`], i.e. the allowlist of a
+/// publishing option locked to the compiled-in stdlib, see
+/// [`crate::publishing_option::encode_allowlist_publishing_option`].
+pub fn current_allowlist_hashes() -> Vec<HashValue> {
+    StdlibScript::all()
+        .iter()
+        .copied()
+        .map(stdlib_script_hash)
+        .collect()
+}
+
+/// The role of the account that must sign a transaction calling a given script.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SenderRole {
+    /// Any account, possibly subject to it holding a balance or a resource the script updates.
+    Any,
+    /// The Diem Root account.
+    DiemRoot,
+    /// A Parent VASP account.
+    ParentVasp,
+    /// The Treasury Compliance account.
+    TreasuryCompliance,
+    /// A Validator account.
+    Validator,
+    /// A Validator Operator account.
+    ValidatorOperator,
+    /// A Parent or Child VASP account.
+    Vasp,
+}
+
+/// Return the role of the account that must send `script`, as documented in its summary. The
+/// role is enforced on-chain, so this only helps picking the signing key up front.
+pub fn required_sender_role(script: StdlibScript) -> SenderRole {
+    use StdlibScript::*;
+    match script {
+        AddValidatorAndReconfigure
+        | CreateValidatorAccount
+        | CreateValidatorOperatorAccount
+        | RemoveValidatorAndReconfigure
+        | RotateAuthenticationKeyWithNonceAdmin
+        | SetValidatorOperatorWithNonceAdmin
+        | UpdateDiemVersion => SenderRole::DiemRoot,
+        Burn
+        | BurnTxnFees
+        | CancelBurn
+        | CreateDesignatedDealer
+        | CreateParentVaspAccount
+        | FreezeAccount
+        | TieredMint
+        | UnfreezeAccount
+        | UpdateDualAttestationLimit
+        | UpdateExchangeRate
+        | UpdateMintingAbility => SenderRole::TreasuryCompliance,
+        CreateChildVaspAccount => SenderRole::ParentVasp,
+        AddRecoveryRotationCapability | CreateRecoveryAddress => SenderRole::Vasp,
+        RegisterValidatorConfig | SetValidatorConfigAndReconfigure => SenderRole::ValidatorOperator,
+        SetValidatorOperator => SenderRole::Validator,
+        AddCurrencyToAccount
+        | PeerToPeerWithMetadata
+        | Preburn
+        | PublishSharedEd25519PublicKey
+        | RotateAuthenticationKey
+        | RotateAuthenticationKeyWithNonce
+        | RotateAuthenticationKeyWithRecoveryAddress
+        | RotateDualAttestationInfo
+        | RotateSharedEd25519PublicKey => SenderRole::Any,
+    }
+}
+
+/// The scripts of the System Administration category, which change the configuration of the
+/// whole chain rather than act on accounts. Stdlib upgrades and publishing option changes belong
+/// to it as well but are write sets, see [`crate::transaction_kind::classify_transaction`].
+const SYSTEM_SCRIPTS: &[StdlibScript] = &[StdlibScript::UpdateDiemVersion];
+
+/// Return whether `script` is a privileged system operation rather than a user or account
+/// administration transaction, e.g. to prioritize or rate limit it apart from them. Scripts that
+/// the Treasury Compliance account sends routinely, such as onboarding, minting or freezing, are
+/// not system scripts, see [`required_sender_role`] for who may send a script.
+pub fn is_system_script(script: StdlibScript) -> bool {
+    SYSTEM_SCRIPTS.contains(&script)
+}
+
+/// Return the name of the script with bytecode `code`, without the `_transaction` suffix used
+/// when formatting transactions.
+pub fn transaction_mnemonic(code: &[u8]) -> Option<&'static str> {
+    StdlibScript::from_code(code).map(StdlibScript::name)
+}
+
+/// Return the `amount` of a `peer_to_peer_with_metadata` script without decoding the rest of its
+/// arguments, or `None` if `script` is not such a script.
+pub fn peer_to_peer_amount(script: &Script) -> Option<u64> {
+    match peer_to_peer_args(script)?.get(1)? {
+        TransactionArgument::U64(amount) => Some(*amount),
+        _ => None,
+    }
+}
+
+/// Return the `payee` of a `peer_to_peer_with_metadata` script without decoding the rest of its
+/// arguments, or `None` if `script` is not such a script.
+pub fn peer_to_peer_recipient(script: &Script) -> Option<AccountAddress> {
+    match peer_to_peer_args(script)?.first()? {
+        TransactionArgument::Address(payee) => Some(*payee),
+        _ => None,
+    }
+}
+
+/// Return the `major` version set by an `update_diem_version` script, e.g. to check a release
+/// transaction before submitting it, or `None` if `script` is not such a script.
+pub fn update_diem_version_major(script: &Script) -> Option<u64> {
+    match StdlibScript::from_code(script.code())? {
+        StdlibScript::UpdateDiemVersion => match script.args().get(1)? {
+            TransactionArgument::U64(major) => Some(*major),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Return whether `a` and `b` call the same stdlib script with the same type arguments and
+/// arguments, comparing the decoded call rather than the code blobs. Scripts whose code is not
+/// a known stdlib script never compare equal.
+pub fn same_call(a: &Script, b: &Script) -> bool {
+    match (
+        StdlibScript::from_code(a.code()),
+        StdlibScript::from_code(b.code()),
+    ) {
+        (Some(x), Some(y)) => x == y && a.ty_args() == b.ty_args() && a.args() == b.args(),
+        _ => false,
+    }
+}
+
+/// Names under which the scripts take the currency they operate on as a type parameter.
+const CURRENCY_TYPE_ARG_NAMES: &[&str] = &["coin_type", "currency", "token"];
+
+/// Return the type arguments of `script`, see [`classified_type_args`] for what they stand for.
+pub fn script_type_args(script: &Script) -> &[TypeTag] {
+    script.ty_args()
+}
+
+/// What a type argument of a script stands for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TypeArgRole {
+    /// The currency the script operates on.
+    Currency,
+    /// Any other type argument, including those of unknown scripts.
+    Other,
+}
+
+/// A type argument of a script, labelled with the name of the type parameter it is passed as.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LabelledTypeArg<'a> {
+    /// `None` if the script is unknown.
+    pub name: Option<&'static str>,
+    pub role: TypeArgRole,
+    pub type_tag: &'a TypeTag,
+}
+
+/// Return the type arguments of `script` labelled with their name in
+/// [`StdlibScript::type_arg_names`] and their role, e.g. so that tooling can tell the currency of
+/// `burn` apart from the other type arguments of a script taking several.
+pub fn classified_type_args(script: &Script) -> Vec<LabelledTypeArg<'_>> {
+    let names = stdlib_script_of(script).map_or(&[][..], StdlibScript::type_arg_names);
+    script
+        .ty_args()
+        .iter()
+        .enumerate()
+        .map(|(i, type_tag)| {
+            let name = names.get(i).copied();
+            let role = match name {
+                Some(name) if CURRENCY_TYPE_ARG_NAMES.contains(&name) => TypeArgRole::Currency,
+                _ => TypeArgRole::Other,
+            };
+            LabelledTypeArg {
+                name,
+                role,
+                type_tag,
+            }
+        })
+        .collect()
+}
+
+/// Return the currencies `script` operates on, e.g. to tag a transaction with the asset it
+/// involves. Scripts without a currency type parameter and unknown scripts touch no currency.
+pub fn script_currencies(script: &Script) -> Vec<TypeTag> {
+    classified_type_args(script)
+        .into_iter()
+        .filter(|type_arg| type_arg.role == TypeArgRole::Currency)
+        .map(|type_arg| type_arg.type_tag.clone())
+        .collect()
+}
+
+/// Return the amount minted by a `tiered_mint` script, e.g. to track mint flows from raw
+/// transactions, or `None` for any other script.
+pub fn decode_mint_amount(script: &Script) -> Option<u64> {
+    match stdlib::ScriptCall::decode(script)? {
+        stdlib::ScriptCall::TieredMint { mint_amount, .. } => Some(mint_amount),
+        _ => None,
+    }
+}
+
+/// Return the amount moved to the preburn area by a `preburn` script, the first step of taking
+/// coins out of circulation, or `None` for any other script.
+pub fn decode_preburn_amount(script: &Script) -> Option<u64> {
+    match stdlib::ScriptCall::decode(script)? {
+        stdlib::ScriptCall::Preburn { amount, .. } => Some(amount),
+        _ => None,
+    }
+}
+
+/// Return `script` with its `sliding_nonce` argument set to `new_nonce`, e.g. to retry an admin
+/// script whose nonce was already used. Fails if `script` is not a stdlib script taking a nonce.
+pub fn with_nonce(script: Script, new_nonce: SlidingNonce) -> Result<Script, BuilderError> {
+    with_u64_arg(script, "sliding_nonce", &["sliding_nonce"], new_nonce.get())
+}
+
+/// Return `script` with its `amount` or `mint_amount` argument set to `new_amount`, e.g. to retry
+/// a transfer with a different amount. Fails if `script` is not a stdlib script taking an amount.
+pub fn with_amount(script: Script, new_amount: u64) -> Result<Script, BuilderError> {
+    with_u64_arg(script, "amount", &["amount", "mint_amount"], new_amount)
+}
+
+/// Replace the first argument of `script` named one of `names` by `value`, reporting `field` if
+/// there is none.
+fn with_u64_arg(
+    script: Script,
+    field: &'static str,
+    names: &[&str],
+    value: u64,
+) -> Result<Script, BuilderError> {
+    let call =
+        stdlib::ScriptCall::decode(&script).ok_or_else(|| BuilderError::MissingArgument {
+            script: format!("<unknown {}>", script_hash(&script)),
+            field,
+        })?;
+    let index = StdlibScript::from_name(call.name())
+        .and_then(|s| s.arg_names().iter().position(|name| names.contains(name)))
+        .ok_or_else(|| BuilderError::MissingArgument {
+            script: call.name().to_string(),
+            field,
+        })?;
+    let ty_args = script.ty_args().to_vec();
+    let (code, mut args) = script.into_inner();
+    args[index] = TransactionArgument::U64(value);
+    Ok(Script::new(code, ty_args, args))
+}
+
+/// The onboarding data carried by a `create_parent_vasp_account` script, see
+/// [`decode_parent_vasp`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParentVaspFields {
+    pub coin_type: TypeTag,
+    pub new_account_address: AccountAddress,
+    pub auth_key_prefix: Vec<u8>,
+    pub human_name: Vec<u8>,
+    pub add_all_currencies: bool,
+}
+
+/// Return the fields of a `create_parent_vasp_account` script, e.g. for a reviewer to confirm
+/// the onboarding data before signing, or `None` for any other script. The base URL and
+/// compliance key of the VASP are not set by this script but later with
+/// `rotate_dual_attestation_info`.
+pub fn decode_parent_vasp(script: &Script) -> Option<ParentVaspFields> {
+    match stdlib::ScriptCall::decode(script)? {
+        stdlib::ScriptCall::CreateParentVaspAccount {
+            coin_type,
+            new_account_address,
+            auth_key_prefix,
+            human_name,
+            add_all_currencies,
+            ..
+        } => Some(ParentVaspFields {
+            coin_type,
+            new_account_address,
+            auth_key_prefix,
+            human_name,
+            add_all_currencies,
+        }),
+        _ => None,
+    }
+}
+
+fn peer_to_peer_args(script: &Script) -> Option<&[TransactionArgument]> {
+    match StdlibScript::from_code(script.code())? {
+        StdlibScript::PeerToPeerWithMetadata => Some(script.args()),
+        _ => None,
+    }
+}
+
+/// Lets a [`stdlib::ScriptCall`] be passed wherever a `Script` is expected.
+impl From<stdlib::ScriptCall> for Script {
+    fn from(call: stdlib::ScriptCall) -> Self {
+        call.encode()
+    }
+}
+
+/// Wraps a [`stdlib::ScriptCall`] into the payload of a transaction. Turning the payload into a
+/// `Transaction::UserTransaction` also needs the sender, its sequence number, gas parameters and a
+/// signature, see [`crate::builders::RawTransactionBuilder`].
+impl From<stdlib::ScriptCall> for TransactionPayload {
+    fn from(call: stdlib::ScriptCall) -> Self {
+        TransactionPayload::Script(call.encode())
+    }
+}
+
+/// Build a `Script` calling `script` with untyped arguments. The arguments are not checked
+/// against the signature of the script, see [`try_encode_stdlib_script`].
+pub fn encode_stdlib_script(
+    script: StdlibScript,
+    type_args: Vec<TypeTag>,
+    args: Vec<TransactionArgument>,
+) -> Script {
+    Script::new(script.compiled_bytes().to_vec(), type_args, args)
+}
+
+/// Like [`encode_stdlib_script`], but checks the number and the kinds of `type_args` and `args`
+/// against the signature of the script first, e.g. to catch a currency type argument left out
+/// before the transaction fails on-chain.
+pub fn try_encode_stdlib_script(
+    script: StdlibScript,
+    type_args: Vec<TypeTag>,
+    args: Vec<TransactionArgument>,
+) -> Result<Script, BuilderError> {
+    fallible::validate_script_args(script, &type_args, &args)?;
+    Ok(encode_stdlib_script(script, type_args, args))
+}
+
+/// Check that `script` deserializes back to itself after being serialized with BCS, e.g. as a
+/// guard after [`encode_stdlib_script`], which accepts any arguments.
+pub fn verify_script_bcs(script: &Script) -> Result<(), BuilderError> {
+    let bytes = bcs::to_bytes(script).map_err(|e| BuilderError::BcsRoundTrip(e.to_string()))?;
+    let decoded: Script =
+        bcs::from_bytes(&bytes).map_err(|e| BuilderError::BcsRoundTrip(e.to_string()))?;
+    if &decoded != script {
+        return Err(BuilderError::BcsRoundTrip(
+            "deserialized script differs from the original".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Return the size of `script` once serialized with BCS, e.g. to estimate gas or to check that a
+/// transaction with large metadata stays under `MAX_TRANSACTION_SIZE_IN_BYTES`.
+pub fn estimated_serialized_size(script: &Script) -> Result<usize, BuilderError> {
+    bcs::serialized_size(script).map_err(|e| BuilderError::BcsRoundTrip(e.to_string()))
+}
+
+/// Return the number of arguments of `script`, not counting its type arguments.
+pub fn arg_count(script: &Script) -> usize {
+    script.args().len()
+}
+
+/// Build a `Script` calling the script named `name`, e.g. from a declarative config. The arguments
+/// are checked with [`crate::fallible::validate_script_args`].
+pub fn encode_by_name(
+    name: &str,
+    type_args: Vec<TypeTag>,
+    args: Vec<TransactionArgument>,
+) -> Result<Script, BuilderError> {
+    let script = StdlibScript::from_name(name).ok_or_else(|| BuilderError::UnknownScript {
+        name: name.to_string(),
+        expected: StdlibScript::all()
+            .iter()
+            .map(|script| script.name())
+            .collect::<Vec<_>>()
+            .join(", "),
+    })?;
+    try_encode_stdlib_script(script, type_args, args)
+}
+
+/// Description of a script and its parameters, e.g. to render a form for it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScriptInfo {
+    pub script: StdlibScript,
+    pub name: &'static str,
+    pub summary: &'static str,
+    pub type_args: &'static [&'static str],
+    /// The name and Move type of each argument, in order.
+    pub args: Vec<(&'static str, &'static str)>,
+}
+
+/// Return a description of every script in [`StdlibScript::all`].
+pub fn script_catalog() -> Vec<ScriptInfo> {
+    StdlibScript::all()
+        .iter()
+        .map(|&script| ScriptInfo {
+            script,
+            name: script.name(),
+            summary: script.summary(),
+            type_args: script.type_arg_names(),
+            args: script
+                .arg_names()
+                .iter()
+                .copied()
+                .zip(script.placeholder_script().args().iter().map(argument_type))
+                .collect(),
+        })
+        .collect()
+}
+
+/// The kind of value expected by a script argument.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArgKind {
+    U8,
+    U64,
+    U128,
+    Address,
+    U8Vector,
+    AddressVector,
+    Bool,
+}
+
+impl ArgKind {
+    /// Return the kind of `arg`.
+    pub fn of(arg: &TransactionArgument) -> Self {
+        match arg {
+            TransactionArgument::U8(_) => ArgKind::U8,
+            TransactionArgument::U64(_) => ArgKind::U64,
+            TransactionArgument::U128(_) => ArgKind::U128,
+            TransactionArgument::Address(_) => ArgKind::Address,
+            TransactionArgument::U8Vector(_) => ArgKind::U8Vector,
+            TransactionArgument::AddressVector(_) => ArgKind::AddressVector,
+            TransactionArgument::Bool(_) => ArgKind::Bool,
+        }
+    }
+
+    /// Return the Move type of arguments of this kind.
+    pub fn move_type(self) -> &'static str {
+        match self {
+            ArgKind::U8 => "u8",
+            ArgKind::U64 => "u64",
+            ArgKind::U128 => "u128",
+            ArgKind::Address => "address",
+            ArgKind::U8Vector => "vector<u8>",
+            ArgKind::AddressVector => "vector<address>",
+            ArgKind::Bool => "bool",
+        }
+    }
+}
+
+/// Machine-readable signature of a script, e.g. to generate encoders in another language.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ScriptSchema {
+    pub name: &'static str,
+    pub type_arg_count: usize,
+    /// The name and kind of each argument, in order.
+    pub args: Vec<(&'static str, ArgKind)>,
+    /// The arguments holding key material or signatures, which should not be logged.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sensitive_args: Vec<&'static str>,
+}
+
+impl ScriptSchema {
+    /// Whether the argument at `index` should be redacted from logs.
+    pub fn is_sensitive(&self, index: usize) -> bool {
+        self.args
+            .get(index)
+            .map_or(false, |(name, _)| self.sensitive_args.contains(name))
+    }
+}
+
+/// Names of the arguments, across all scripts, that hold public keys or signatures.
+const SENSITIVE_ARG_NAMES: &[&str] = &[
+    "consensus_pubkey",
+    "metadata_signature",
+    "new_key",
+    "public_key",
+];
+
+/// The schemas are derived from the generated encoders, see [`StdlibScript::placeholder_script`],
+/// so they cannot drift from them.
+static ARGUMENT_SCHEMAS: Lazy<Vec<ScriptSchema>> = Lazy::new(|| {
+    StdlibScript::all()
+        .iter()
+        .map(|script| {
+            let placeholder = script.placeholder_script();
+            ScriptSchema {
+                name: script.name(),
+                type_arg_count: placeholder.ty_args().len(),
+                args: script
+                    .arg_names()
+                    .iter()
+                    .copied()
+                    .zip(placeholder.args().iter().map(ArgKind::of))
+                    .collect(),
+                sensitive_args: script
+                    .arg_names()
+                    .iter()
+                    .copied()
+                    .filter(|name| SENSITIVE_ARG_NAMES.contains(name))
+                    .collect(),
+            }
+        })
+        .collect()
+});
+
+/// Return the schema of every script in [`StdlibScript::all`], in the same order.
+pub fn argument_schemas() -> &'static [ScriptSchema] {
+    &ARGUMENT_SCHEMAS
+}
+
+/// Return the schema of `script`, see [`argument_schemas`].
+pub fn argument_schema(script: StdlibScript) -> &'static ScriptSchema {
+    argument_schemas()
+        .iter()
+        .find(|schema| schema.name == script.name())
+        .expect("Every script has a schema")
+}
+
+/// The value of a script argument whose kind was checked by [`expect_args`]. Vectors borrow from
+/// the arguments.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TypedArg<'a> {
+    U8(u8),
+    U64(u64),
+    U128(u128),
+    Address(AccountAddress),
+    U8Vector(&'a [u8]),
+    AddressVector(&'a [AccountAddress]),
+    Bool(bool),
+}
+
+impl<'a> From<&'a TransactionArgument> for TypedArg<'a> {
+    fn from(arg: &'a TransactionArgument) -> Self {
+        match arg {
+            TransactionArgument::U8(value) => TypedArg::U8(*value),
+            TransactionArgument::U64(value) => TypedArg::U64(*value),
+            TransactionArgument::U128(value) => TypedArg::U128(*value),
+            TransactionArgument::Address(address) => TypedArg::Address(*address),
+            TransactionArgument::U8Vector(bytes) => TypedArg::U8Vector(bytes),
+            TransactionArgument::AddressVector(addresses) => TypedArg::AddressVector(addresses),
+            TransactionArgument::Bool(value) => TypedArg::Bool(*value),
+        }
+    }
+}
+
+/// Check that `args` has the arity and the kinds of the arguments of `schema`, and return them
+/// as typed values in the same order.
+pub fn expect_args<'a>(
+    args: &'a [TransactionArgument],
+    schema: &ScriptSchema,
+) -> Result<Vec<TypedArg<'a>>, BuilderError> {
+    if args.len() != schema.args.len() {
+        return Err(BuilderError::WrongArgumentCount {
+            script: schema.name,
+            kind: "arguments",
+            got: args.len(),
+            expected: schema.args.len(),
+        });
+    }
+    args.iter()
+        .zip(&schema.args)
+        .map(|(arg, &(name, kind))| {
+            if ArgKind::of(arg) != kind {
+                return Err(BuilderError::WrongArgumentType {
+                    script: schema.name,
+                    name,
+                    got: argument_type(arg),
+                    expected: kind.move_type(),
+                });
+            }
+            Ok(TypedArg::from(arg))
+        })
+        .collect()
+}
+
+/// Return the Move type of a transaction argument.
+pub(crate) fn argument_type(arg: &TransactionArgument) -> &'static str {
+    ArgKind::of(arg).move_type()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdlib::ScriptCall;
+    use diem_types::account_config::gas_type_tag;
+    use move_binary_format::file_format::CompiledScript;
+    use proptest::{collection::vec, prelude::*, sample::select};
+
+    /// Generate a value of the same type as `arg`, biased towards the edge cases.
+    fn arbitrary_argument(arg: &TransactionArgument) -> BoxedStrategy<TransactionArgument> {
+        match arg {
+            TransactionArgument::U64(_) => prop_oneof![Just(0), Just(u64::MAX), any::<u64>()]
+                .prop_map(TransactionArgument::U64)
+                .boxed(),
+            TransactionArgument::Address(_) => prop_oneof![
+                Just(AccountAddress::ZERO),
+                any::<[u8; AccountAddress::LENGTH]>().prop_map(AccountAddress::new),
+            ]
+            .prop_map(TransactionArgument::Address)
+            .boxed(),
+            TransactionArgument::U8Vector(_) => prop_oneof![Just(vec![]), vec(any::<u8>(), 0..64)]
+                .prop_map(TransactionArgument::U8Vector)
+                .boxed(),
+            TransactionArgument::Bool(_) => {
+                any::<bool>().prop_map(TransactionArgument::Bool).boxed()
+            }
+            _ => panic!("No stdlib script takes an argument like {:?}", arg),
+        }
+    }
+
+    fn arbitrary_call(
+    ) -> impl Strategy<Value = (StdlibScript, Vec<TypeTag>, Vec<TransactionArgument>)> {
+        select(StdlibScript::all()).prop_flat_map(|script| {
+            let placeholder = script.placeholder_script();
+            let type_args = vec(
+                prop_oneof![Just(TypeTag::Bool), Just(gas_type_tag())],
+                placeholder.ty_args().len(),
+            );
+            let args: Vec<_> = placeholder.args().iter().map(arbitrary_argument).collect();
+            (Just(script), type_args, args)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_encode_decode_roundtrip((script, type_args, args) in arbitrary_call()) {
+            let encoded = encode_stdlib_script(script, type_args, args);
+            prop_assert_eq!(verify_script_bcs(&encoded), Ok(()));
+            let call = ScriptCall::decode(&encoded).expect("Failed to decode an encoded script");
+            prop_assert_eq!(call.name(), script.name());
+            prop_assert_eq!(call.encode(), encoded);
+        }
+    }
+
+    #[test]
+    fn test_names_match_decoder() {
+        for script in StdlibScript::all() {
+            let call = ScriptCall::decode(&script.placeholder_script())
+                .unwrap_or_else(|| panic!("Failed to decode {}", script.name()));
+            assert_eq!(call.name(), script.name());
+        }
+    }
+
+    #[test]
+    fn test_all_is_complete() {
+        let unique: std::collections::BTreeSet<_> = StdlibScript::all().iter().collect();
+        assert_eq!(unique.len(), StdlibScript::all().len());
+        assert_eq!(SCRIPTS_BY_CODE.len(), StdlibScript::all().len());
+    }
+
+    #[test]
+    fn test_compiled_bytes_match_encoders() {
+        for script in StdlibScript::all() {
+            assert_eq!(
+                script.compiled_bytes(),
+                script.placeholder_script().code(),
+                "{} does not match its encoder",
+                script.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_compiled_bytes_deserialize() {
+        for script in StdlibScript::all() {
+            let bytes = script.compiled_bytes();
+            assert!(!bytes.is_empty(), "{} has no compiled bytes", script.name());
+            if let Err(e) = CompiledScript::deserialize(bytes) {
+                panic!("{} does not deserialize: {:?}", script.name(), e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parameter_names_match_arity() {
+        for script in StdlibScript::all() {
+            let placeholder = script.placeholder_script();
+            assert_eq!(script.type_arg_names().len(), placeholder.ty_args().len());
+            assert_eq!(script.arg_names().len(), placeholder.args().len());
+            assert_eq!(StdlibScript::from_name(script.name()), Some(*script));
+        }
+    }
+
+    #[test]
+    fn test_script_catalog() {
+        let catalog = script_catalog();
+        assert_eq!(catalog.len(), StdlibScript::all().len());
+        let p2p = catalog
+            .iter()
+            .find(|info| info.script == StdlibScript::PeerToPeerWithMetadata)
+            .unwrap();
+        assert_eq!(p2p.type_args, &["currency"]);
+        assert_eq!(
+            p2p.args,
+            vec![
+                ("payee", "address"),
+                ("amount", "u64"),
+                ("metadata", "vector<u8>"),
+                ("metadata_signature", "vector<u8>"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_argument_schemas() {
+        let schemas = argument_schemas();
+        assert_eq!(schemas.len(), StdlibScript::all().len());
+        for (schema, script) in schemas.iter().zip(StdlibScript::all()) {
+            assert_eq!(schema.name, script.name());
+            assert_eq!(schema.type_arg_count, script.type_arg_names().len());
+            assert_eq!(schema.args.len(), script.arg_names().len());
+        }
+        let preburn = schemas.iter().find(|s| s.name == "preburn").unwrap();
+        assert_eq!(preburn.type_arg_count, 1);
+        assert_eq!(preburn.args, vec![("amount", ArgKind::U64)]);
+        assert_eq!(
+            serde_json::to_value(preburn).unwrap(),
+            serde_json::json!({
+                "name": "preburn",
+                "type_arg_count": 1,
+                "args": [["amount", "u64"]],
+            })
+        );
+    }
+
+    #[test]
+    fn test_sensitive_args() {
+        let schema = argument_schema(StdlibScript::PeerToPeerWithMetadata);
+        assert_eq!(schema.sensitive_args, vec!["metadata_signature"]);
+        assert!(!schema.is_sensitive(0));
+        assert!(!schema.is_sensitive(2));
+        assert!(schema.is_sensitive(3));
+        assert!(!schema.is_sensitive(4));
+        let schema = argument_schema(StdlibScript::RotateDualAttestationInfo);
+        assert_eq!(schema.sensitive_args, vec!["new_key"]);
+        for schema in argument_schemas() {
+            for name in &schema.sensitive_args {
+                assert!(schema.args.iter().any(|(arg, _)| arg == name));
+            }
+        }
+    }
+
+    #[test]
+    fn test_expect_args() {
+        let schema = argument_schema(StdlibScript::PeerToPeerWithMetadata);
+        let payee = AccountAddress::random();
+        let args = vec![
+            TransactionArgument::Address(payee),
+            TransactionArgument::U64(10),
+            TransactionArgument::U8Vector(vec![1, 2]),
+            TransactionArgument::U8Vector(vec![]),
+        ];
+        assert_eq!(
+            expect_args(&args, schema),
+            Ok(vec![
+                TypedArg::Address(payee),
+                TypedArg::U64(10),
+                TypedArg::U8Vector(&[1, 2]),
+                TypedArg::U8Vector(&[]),
+            ])
+        );
+        assert_eq!(
+            expect_args(&args[..3], schema),
+            Err(BuilderError::WrongArgumentCount {
+                script: "peer_to_peer_with_metadata",
+                kind: "arguments",
+                got: 3,
+                expected: 4,
+            })
+        );
+        assert_eq!(
+            expect_args(
+                &[TransactionArgument::Bool(true)],
+                argument_schema(StdlibScript::Preburn)
+            ),
+            Err(BuilderError::WrongArgumentType {
+                script: "preburn",
+                name: "amount",
+                got: "bool",
+                expected: "u64",
+            })
+        );
+    }
+
+    #[test]
+    fn test_script_id() {
+        use StdlibScript::*;
+        // The ids are pinned: changing any of them breaks the metrics recorded with them.
+        let pinned = [
+            (AddCurrencyToAccount, 0),
+            (AddRecoveryRotationCapability, 1),
+            (AddValidatorAndReconfigure, 2),
+            (Burn, 3),
+            (BurnTxnFees, 4),
+            (CancelBurn, 5),
+            (CreateChildVaspAccount, 6),
+            (CreateDesignatedDealer, 7),
+            (CreateParentVaspAccount, 8),
+            (CreateRecoveryAddress, 9),
+            (CreateValidatorAccount, 10),
+            (CreateValidatorOperatorAccount, 11),
+            (FreezeAccount, 12),
+            (PeerToPeerWithMetadata, 13),
+            (Preburn, 14),
+            (PublishSharedEd25519PublicKey, 15),
+            (RegisterValidatorConfig, 16),
+            (RemoveValidatorAndReconfigure, 17),
+            (RotateAuthenticationKey, 18),
+            (RotateAuthenticationKeyWithNonce, 19),
+            (RotateAuthenticationKeyWithNonceAdmin, 20),
+            (RotateAuthenticationKeyWithRecoveryAddress, 21),
+            (RotateDualAttestationInfo, 22),
+            (RotateSharedEd25519PublicKey, 23),
+            (SetValidatorConfigAndReconfigure, 24),
+            (SetValidatorOperator, 25),
+            (SetValidatorOperatorWithNonceAdmin, 26),
+            (TieredMint, 27),
+            (UnfreezeAccount, 28),
+            (UpdateDiemVersion, 29),
+            (UpdateDualAttestationLimit, 30),
+            (UpdateExchangeRate, 31),
+            (UpdateMintingAbility, 32),
+        ];
+        assert_eq!(pinned.len(), StdlibScript::all().len());
+        for (script, id) in pinned.iter() {
+            assert_eq!(script_id(*script), *id);
+            assert_eq!(script_from_id(*id), Some(*script));
+        }
+        assert_eq!(script_from_id(pinned.len() as u16), None);
+    }
+
+    #[test]
+    fn test_stdlib_script_of() {
+        let script = stdlib::encode_preburn_script(TypeTag::Bool, 10);
+        assert_eq!(stdlib_script_of(&script), Some(StdlibScript::Preburn));
+        assert!(is_current_stdlib(&script));
+        let mut code = script.code().to_vec();
+        code.push(0);
+        let stale = Script::new(code, script.ty_args().to_vec(), script.args().to_vec());
+        assert_eq!(stdlib_script_of(&stale), None);
+        assert!(!is_current_stdlib(&stale));
+    }
+
+    #[test]
+    fn test_script_hash() {
+        let script = stdlib::encode_preburn_script(TypeTag::Bool, 10);
+        assert_eq!(script_hash(&script), HashValue::sha3_256_of(script.code()));
+        assert_eq!(
+            stdlib_script_hash(StdlibScript::Preburn),
+            script_hash(&script)
+        );
+    }
+
+    #[test]
+    fn test_current_allowlist_hashes() {
+        let hashes = current_allowlist_hashes();
+        assert_eq!(hashes.len(), 33);
+        assert_eq!(
+            hashes
+                .iter()
+                .collect::<std::collections::HashSet<_>>()
+                .len(),
+            StdlibScript::all().len()
+        );
+        assert!(hashes.contains(&stdlib_script_hash(StdlibScript::PeerToPeerWithMetadata)));
+    }
+
+    #[test]
+    fn test_transaction_mnemonic() {
+        for script in StdlibScript::all() {
+            assert_eq!(
+                StdlibScript::from_code(script.compiled_bytes()),
+                Some(*script)
+            );
+            assert_eq!(
+                transaction_mnemonic(script.compiled_bytes()),
+                Some(script.name())
+            );
+        }
+        assert_eq!(transaction_mnemonic(&[]), None);
+    }
+
+    #[test]
+    fn test_required_sender_role() {
+        assert_eq!(
+            required_sender_role(StdlibScript::TieredMint),
+            SenderRole::TreasuryCompliance
+        );
+        assert_eq!(
+            required_sender_role(StdlibScript::UpdateDiemVersion),
+            SenderRole::DiemRoot
+        );
+        assert_eq!(
+            required_sender_role(StdlibScript::CreateChildVaspAccount),
+            SenderRole::ParentVasp
+        );
+        assert_eq!(
+            required_sender_role(StdlibScript::PeerToPeerWithMetadata),
+            SenderRole::Any
+        );
+    }
+
+    #[test]
+    fn test_is_system_script() {
+        let system_scripts: Vec<_> = StdlibScript::all()
+            .iter()
+            .copied()
+            .filter(|script| is_system_script(*script))
+            .collect();
+        assert_eq!(system_scripts, vec![StdlibScript::UpdateDiemVersion]);
+    }
+
+    #[test]
+    fn test_peer_to_peer_introspection() {
+        let payee = AccountAddress::random();
+        let script = stdlib::encode_peer_to_peer_with_metadata_script(
+            TypeTag::Bool,
+            payee,
+            u64::MAX,
+            vec![],
+            vec![],
+        );
+        assert_eq!(peer_to_peer_amount(&script), Some(u64::MAX));
+        assert_eq!(peer_to_peer_recipient(&script), Some(payee));
+
+        let script = stdlib::encode_preburn_script(TypeTag::Bool, 10);
+        assert_eq!(peer_to_peer_amount(&script), None);
+        assert_eq!(peer_to_peer_recipient(&script), None);
+    }
+
+    #[test]
+    fn test_update_diem_version_major() {
+        let script = stdlib::encode_update_diem_version_script(0, 3);
+        assert_eq!(update_diem_version_major(&script), Some(3));
+        let script = stdlib::encode_preburn_script(TypeTag::Bool, 3);
+        assert_eq!(update_diem_version_major(&script), None);
+    }
+
+    #[test]
+    fn test_same_call() {
+        let script = stdlib::encode_preburn_script(TypeTag::Bool, 10);
+        assert!(same_call(&script, &script.clone()));
+        assert!(!same_call(
+            &script,
+            &stdlib::encode_preburn_script(TypeTag::Bool, 11)
+        ));
+        assert!(!same_call(
+            &script,
+            &stdlib::encode_preburn_script(TypeTag::U8, 10)
+        ));
+        let unknown = Script::new(
+            vec![],
+            vec![TypeTag::Bool],
+            vec![TransactionArgument::U64(10)],
+        );
+        assert!(!same_call(&unknown, &unknown));
+    }
+
+    #[test]
+    fn test_script_currencies() {
+        let currency = gas_type_tag();
+        let script = stdlib::encode_peer_to_peer_with_metadata_script(
+            currency.clone(),
+            AccountAddress::random(),
+            1,
+            vec![],
+            vec![],
+        );
+        assert_eq!(script_currencies(&script), vec![currency]);
+        let script = stdlib::encode_update_diem_version_script(0, 3);
+        assert!(script_currencies(&script).is_empty());
+        let script = Script::new(vec![], vec![TypeTag::Bool], vec![]);
+        assert!(script_currencies(&script).is_empty());
+    }
+
+    #[test]
+    fn test_classified_type_args() {
+        let script = stdlib::encode_burn_script(gas_type_tag(), 1, AccountAddress::ZERO);
+        assert_eq!(script_type_args(&script), &[gas_type_tag()]);
+        assert_eq!(
+            classified_type_args(&script),
+            vec![LabelledTypeArg {
+                name: Some("token"),
+                role: TypeArgRole::Currency,
+                type_tag: &gas_type_tag(),
+            }]
+        );
+        let script = stdlib::encode_update_diem_version_script(0, 3);
+        assert!(classified_type_args(&script).is_empty());
+        let script = Script::new(vec![], vec![TypeTag::Bool], vec![]);
+        assert_eq!(
+            classified_type_args(&script),
+            vec![LabelledTypeArg {
+                name: None,
+                role: TypeArgRole::Other,
+                type_tag: &TypeTag::Bool,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_decode_parent_vasp() {
+        let address = AccountAddress::random();
+        let script = stdlib::encode_create_parent_vasp_account_script(
+            gas_type_tag(),
+            1,
+            address,
+            vec![2; 16],
+            b"VASP".to_vec(),
+            true,
+        );
+        assert_eq!(
+            decode_parent_vasp(&script),
+            Some(ParentVaspFields {
+                coin_type: gas_type_tag(),
+                new_account_address: address,
+                auth_key_prefix: vec![2; 16],
+                human_name: b"VASP".to_vec(),
+                add_all_currencies: true,
+            })
+        );
+        let script = stdlib::encode_preburn_script(TypeTag::Bool, 7);
+        assert_eq!(decode_parent_vasp(&script), None);
+    }
+
+    #[test]
+    fn test_decode_mint_and_preburn_amounts() {
+        let mint = stdlib::encode_tiered_mint_script(TypeTag::Bool, 1, AccountAddress::ZERO, 42, 0);
+        let preburn = stdlib::encode_preburn_script(TypeTag::Bool, 7);
+        assert_eq!(decode_mint_amount(&mint), Some(42));
+        assert_eq!(decode_mint_amount(&preburn), None);
+        assert_eq!(decode_preburn_amount(&preburn), Some(7));
+        assert_eq!(decode_preburn_amount(&mint), None);
+    }
+
+    #[test]
+    fn test_encode_by_name() {
+        assert_eq!(
+            encode_by_name(
+                "preburn",
+                vec![TypeTag::Bool],
+                vec![TransactionArgument::U64(10)]
+            ),
+            Ok(stdlib::encode_preburn_script(TypeTag::Bool, 10))
+        );
+        match encode_by_name("mint", vec![], vec![]) {
+            Err(BuilderError::UnknownScript { name, expected }) => {
+                assert_eq!(name, "mint");
+                assert!(expected.contains("tiered_mint"));
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+        assert!(encode_by_name("preburn", vec![TypeTag::Bool], vec![]).is_err());
+    }
+
+    #[test]
+    fn test_script_from_call() {
+        let script = stdlib::encode_preburn_script(TypeTag::Bool, 10);
+        let call = ScriptCall::decode(&script).unwrap();
+        assert_eq!(
+            call,
+            ScriptCall::Preburn {
+                token: TypeTag::Bool,
+                amount: 10
+            }
+        );
+        assert_eq!(Script::from(call.clone()), script);
+        assert_eq!(
+            TransactionPayload::from(call),
+            TransactionPayload::Script(script)
+        );
+    }
+
+    #[test]
+    fn test_encode_stdlib_script() {
+        let script = encode_stdlib_script(
+            StdlibScript::Preburn,
+            vec![TypeTag::Bool],
+            vec![TransactionArgument::U64(10)],
+        );
+        assert_eq!(script, stdlib::encode_preburn_script(TypeTag::Bool, 10));
+        assert_eq!(verify_script_bcs(&script), Ok(()));
+    }
+
+    #[test]
+    fn test_try_encode_stdlib_script() {
+        let args = vec![TransactionArgument::U64(10)];
+        assert_eq!(
+            try_encode_stdlib_script(StdlibScript::Preburn, vec![TypeTag::Bool], args.clone()),
+            Ok(stdlib::encode_preburn_script(TypeTag::Bool, 10))
+        );
+        assert_eq!(
+            try_encode_stdlib_script(StdlibScript::Preburn, vec![], args),
+            Err(BuilderError::WrongArgumentCount {
+                script: "preburn",
+                kind: "type arguments",
+                got: 0,
+                expected: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_estimated_serialized_size() {
+        assert_eq!(
+            estimated_serialized_size(&Script::new(vec![], vec![], vec![])),
+            Ok(3)
+        );
+        let p2p = |metadata| {
+            stdlib::encode_peer_to_peer_with_metadata_script(
+                gas_type_tag(),
+                AccountAddress::ZERO,
+                1,
+                metadata,
+                vec![],
+            )
+        };
+        // 200 bytes of metadata and one more byte for their length prefix.
+        assert_eq!(
+            estimated_serialized_size(&p2p(vec![0; 200])).unwrap()
+                - estimated_serialized_size(&p2p(vec![])).unwrap(),
+            201
+        );
+    }
+
+    #[test]
+    fn test_with_nonce_and_amount() {
+        let script = stdlib::encode_burn_script(gas_type_tag(), 1, AccountAddress::ZERO);
+        assert_eq!(
+            with_nonce(script, SlidingNonce::new(2).unwrap()),
+            Ok(stdlib::encode_burn_script(
+                gas_type_tag(),
+                2,
+                AccountAddress::ZERO
+            ))
+        );
+        let script =
+            stdlib::encode_tiered_mint_script(gas_type_tag(), 1, AccountAddress::ZERO, 10, 0);
+        assert_eq!(
+            with_amount(
+                with_nonce(script, SlidingNonce::new(5).unwrap()).unwrap(),
+                20
+            ),
+            Ok(stdlib::encode_tiered_mint_script(
+                gas_type_tag(),
+                5,
+                AccountAddress::ZERO,
+                20,
+                0
+            ))
+        );
+
+        let payee = AccountAddress::random();
+        let script = stdlib::encode_peer_to_peer_with_metadata_script(
+            gas_type_tag(),
+            payee,
+            10,
+            vec![1],
+            vec![],
+        );
+        assert_eq!(
+            with_amount(script.clone(), 11),
+            Ok(stdlib::encode_peer_to_peer_with_metadata_script(
+                gas_type_tag(),
+                payee,
+                11,
+                vec![1],
+                vec![],
+            ))
+        );
+        assert_eq!(
+            with_nonce(script, SlidingNonce::new(2).unwrap()),
+            Err(BuilderError::MissingArgument {
+                script: "peer_to_peer_with_metadata".to_string(),
+                field: "sliding_nonce",
+            })
+        );
+
+        let script = Script::new(vec![0xca, 0xfe], vec![], vec![TransactionArgument::U64(1)]);
+        assert_eq!(
+            with_amount(script.clone(), 2),
+            Err(BuilderError::MissingArgument {
+                script: format!("<unknown {}>", script_hash(&script)),
+                field: "amount",
+            })
+        );
+    }
+
+    #[test]
+    fn test_peer_to_peer_golden_bytes() {
+        let payee = AccountAddress::new([0xdd; AccountAddress::LENGTH]);
+        let script = stdlib::encode_peer_to_peer_with_metadata_script(
+            TypeTag::Bool,
+            payee,
+            1_000_000,
+            vec![0xab],
+            vec![],
+        );
+        let bytes = bcs::to_bytes(&script).unwrap();
+        // Everything after the code: the type arguments, then the arguments, each prefixed with
+        // the index of its variant. Integers are little-endian.
+        let expected_tail = [
+            &[0x01, 0x00][..],
+            &[0x04],
+            &[0x03],
+            &[0xdd; AccountAddress::LENGTH],
+            &[0x01, 0x40, 0x42, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00],
+            &[0x04, 0x01, 0xab],
+            &[0x04, 0x00],
+        ]
+        .concat();
+        let (code, tail) = bytes.split_at(bytes.len() - expected_tail.len());
+        assert_eq!(tail, &expected_tail[..]);
+        assert!(code.ends_with(script.code()));
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shortcuts for tests, only available with the `testing` feature so that they cannot leak into
+//! wallets. They fill in the arguments that tests rarely care about, such as sliding nonces and
+//! human names, and always use the gas currency.
+
+use crate::{fallible::auth_key_and_address, stdlib};
+use diem_crypto::ed25519::Ed25519PublicKey;
+use diem_types::{
+    account_address::AccountAddress, account_config::gas_type_tag, transaction::Script,
+};
+
+/// Create a Parent VASP account holding all currencies and authenticated by `public_key`. Returns
+/// the address of the new account with the script.
+pub fn encode_create_testing_account_script(
+    public_key: &Ed25519PublicKey,
+) -> (AccountAddress, Script) {
+    let (auth_key_prefix, address) = auth_key_and_address(public_key);
+    let script = stdlib::encode_create_parent_vasp_account_script(
+        gas_type_tag(),
+        0,
+        address,
+        auth_key_prefix,
+        b"testing".to_vec(),
+        true,
+    );
+    (address, script)
+}
+
+/// Mint `amount` coins to the Designated Dealer at `designated_dealer_address`, in its first mint
+/// tier.
+pub fn encode_testing_mint_script(
+    designated_dealer_address: AccountAddress,
+    amount: u64,
+) -> Script {
+    stdlib::encode_tiered_mint_script(gas_type_tag(), 0, designated_dealer_address, amount, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdlib::ScriptCall;
+    use diem_crypto::{ed25519::Ed25519PrivateKey, PrivateKey, Uniform};
+
+    #[test]
+    fn test_encode_create_testing_account_script() {
+        let public_key = Ed25519PrivateKey::generate_for_testing().public_key();
+        let (address, script) = encode_create_testing_account_script(&public_key);
+        match ScriptCall::decode(&script) {
+            Some(ScriptCall::CreateParentVaspAccount {
+                new_account_address,
+                ..
+            }) => assert_eq!(new_account_address, address),
+            call => panic!("Unexpected script {:?}", call),
+        }
+    }
+}
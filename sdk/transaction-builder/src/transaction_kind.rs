@@ -0,0 +1,103 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Classification of the transactions in a block, e.g. to route a mixed stream of transactions
+//! without matching on the nested `Transaction` and `TransactionPayload` enums.
+
+use crate::stdlib_script::StdlibScript;
+use diem_types::transaction::{Transaction, TransactionPayload};
+
+/// What a transaction does, as far as it can be told without executing it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransactionKind {
+    /// A user transaction running one of the stdlib scripts.
+    UserScript(StdlibScript),
+    /// A genesis or user transaction applying a write set.
+    WriteSet,
+    /// The transaction updating the block metadata at the start of every block.
+    BlockMetadata,
+    /// A user transaction running any other script, calling a script function or publishing a
+    /// module.
+    Unknown,
+}
+
+/// Return the kind of `txn`.
+pub fn classify_transaction(txn: &Transaction) -> TransactionKind {
+    match txn {
+        Transaction::UserTransaction(signed_txn) => match signed_txn.payload() {
+            TransactionPayload::Script(script) => StdlibScript::from_code(script.code())
+                .map_or(TransactionKind::Unknown, TransactionKind::UserScript),
+            TransactionPayload::WriteSet(_) => TransactionKind::WriteSet,
+            TransactionPayload::Module(_) | TransactionPayload::ScriptFunction(_) => {
+                TransactionKind::Unknown
+            }
+        },
+        Transaction::GenesisTransaction(_) => TransactionKind::WriteSet,
+        Transaction::BlockMetadata(_) => TransactionKind::BlockMetadata,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdlib;
+    use diem_crypto::{ed25519::Ed25519PrivateKey, HashValue, PrivateKey};
+    use diem_types::{
+        account_address::AccountAddress,
+        account_config::GAS_NAME,
+        block_metadata::BlockMetadata,
+        chain_id::ChainId,
+        transaction::{ChangeSet, RawTransaction, Script, WriteSetPayload},
+        write_set::WriteSetMut,
+    };
+    use move_core_types::language_storage::TypeTag;
+    use std::convert::TryFrom;
+
+    fn user_transaction(script: Script) -> Transaction {
+        let private_key = Ed25519PrivateKey::try_from(&[1u8; 32][..]).unwrap();
+        let raw_txn = RawTransaction::new_script(
+            AccountAddress::random(),
+            0,
+            script,
+            0,
+            0,
+            GAS_NAME.to_owned(),
+            0,
+            ChainId::test(),
+        );
+        Transaction::UserTransaction(
+            raw_txn
+                .sign(&private_key, private_key.public_key())
+                .unwrap()
+                .into_inner(),
+        )
+    }
+
+    #[test]
+    fn test_classify_transaction() {
+        assert_eq!(
+            classify_transaction(&user_transaction(stdlib::encode_preburn_script(
+                TypeTag::Bool,
+                1
+            ))),
+            TransactionKind::UserScript(StdlibScript::Preburn)
+        );
+        assert_eq!(
+            classify_transaction(&user_transaction(Script::new(vec![], vec![], vec![]))),
+            TransactionKind::Unknown
+        );
+        let change_set = ChangeSet::new(WriteSetMut::new(vec![]).freeze().unwrap(), vec![]);
+        assert_eq!(
+            classify_transaction(&Transaction::GenesisTransaction(WriteSetPayload::Direct(
+                change_set
+            ))),
+            TransactionKind::WriteSet
+        );
+        let block_metadata =
+            BlockMetadata::new(HashValue::zero(), 0, 0, vec![], AccountAddress::random());
+        assert_eq!(
+            classify_transaction(&Transaction::BlockMetadata(block_metadata)),
+            TransactionKind::BlockMetadata
+        );
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `wasm-bindgen` bindings for the most common scripts, so that browser wallets can build
+//! transactions without reimplementing script encoding.
+//!
+//! Addresses are `0x`-prefixed hex strings, byte vectors are hex strings and currencies are
+//! currency codes such as `"GAS"`. Every function returns the BCS-serialized `Script`.
+
+use crate::fallible;
+use diem_types::{
+    account_address::AccountAddress,
+    account_config::{from_currency_code_string, type_tag_for_currency_code},
+    transaction::Script,
+};
+use move_core_types::language_storage::TypeTag;
+use std::fmt::Display;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn encode_peer_to_peer_with_metadata_script(
+    currency: &str,
+    payee: &str,
+    amount: u64,
+    metadata: &str,
+    metadata_signature: &str,
+) -> Result<Vec<u8>, JsValue> {
+    serialize(fallible::try_encode_peer_to_peer_with_metadata_script(
+        parse_currency(currency)?,
+        parse_address(payee)?,
+        amount,
+        parse_bytes(metadata)?,
+        parse_bytes(metadata_signature)?,
+    ))
+}
+
+#[wasm_bindgen]
+pub fn encode_add_currency_to_account_script(currency: &str) -> Result<Vec<u8>, JsValue> {
+    serialize(fallible::try_encode_add_currency_to_account_script(
+        parse_currency(currency)?,
+    ))
+}
+
+#[wasm_bindgen]
+pub fn encode_rotate_authentication_key_script(new_key: &str) -> Result<Vec<u8>, JsValue> {
+    serialize(fallible::try_encode_rotate_authentication_key_script(
+        parse_bytes(new_key)?,
+    ))
+}
+
+fn to_js_error<E: Display>(error: E) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+fn parse_currency(currency: &str) -> Result<TypeTag, JsValue> {
+    from_currency_code_string(currency)
+        .map(type_tag_for_currency_code)
+        .map_err(to_js_error)
+}
+
+fn parse_address(address: &str) -> Result<AccountAddress, JsValue> {
+    AccountAddress::from_hex_literal(address).map_err(to_js_error)
+}
+
+fn parse_bytes(bytes: &str) -> Result<Vec<u8>, JsValue> {
+    hex::decode(bytes.trim_start_matches("0x")).map_err(to_js_error)
+}
+
+fn serialize<E: Display>(script: Result<Script, E>) -> Result<Vec<u8>, JsValue> {
+    bcs::to_bytes(&script.map_err(to_js_error)?).map_err(to_js_error)
+}
@@ -0,0 +1,74 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Timings of the stdlib upgrade encoder, reported by criterion, along with the number of
+//! allocations it makes, counted by a wrapper around the system allocator.
+
+use cli::client_proxy::{try_encode_stdlib_upgrade_transaction, UpgradeEncoder};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Counts every allocation and reallocation before forwarding it to the system allocator.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Run `f` `iters` times and print the average number of allocations it made per run.
+fn report_allocations<R>(name: &str, iters: u64, mut f: impl FnMut() -> R) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..iters {
+        black_box(f());
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!(
+        "{}: {:.2} allocations/iter over {} iterations",
+        name,
+        allocations as f64 / iters as f64,
+        iters
+    );
+}
+
+/// Serializes and hashes every module of the stdlib built from source.
+fn upgrade_encoder(c: &mut Criterion) {
+    let modules = diem_framework::modules();
+    let from_modules = || UpgradeEncoder::from_modules(modules).unwrap();
+    c.bench_function("upgrade_encoder_from_modules", |b| b.iter(from_modules));
+    report_allocations("upgrade_encoder_from_modules", 100, from_modules);
+
+    let encoder = from_modules();
+    let encode = || encoder.encode().unwrap();
+    c.bench_function("upgrade_encoder_encode", |b| b.iter(encode));
+    report_allocations("upgrade_encoder_encode", 100, encode);
+}
+
+/// The whole upgrade, as built by the `dev upgrade_stdlib` command.
+fn stdlib_upgrade(c: &mut Criterion) {
+    let encode = || try_encode_stdlib_upgrade_transaction().unwrap();
+    c.bench_function("encode_stdlib_upgrade_transaction", |b| b.iter(encode));
+    report_allocations("encode_stdlib_upgrade_transaction", 100, encode);
+}
+
+criterion_group!(benches, upgrade_encoder, stdlib_upgrade);
+criterion_main!(benches);
@@ -12,11 +12,16 @@ use diem_client::{WaitForTransactionError, views::{self, WaypointView}};
 use diem_crypto::{
     ed25519::{Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature},
     test_utils::KeyPair,
+    HashValue,
 };
 use diem_json_rpc_client::views::{OracleUpgradeStateView, TowerStateResourceView};
 use diem_logger::prelude::{error, info};
 use diem_temppath::TempPath;
-use diem_transaction_builder::stdlib as transaction_builder;
+use diem_transaction_builder::{
+    builders::{encode_changeset_with_events, write_set_from_pairs},
+    error::BuilderError,
+    stdlib as transaction_builder,
+};
 use diem_types::{
     access_path::AccessPath,
     account_address::AccountAddress,
@@ -40,9 +45,11 @@ use diem_types::{
         TransactionArgument, TransactionPayload, Version, WriteSetPayload,
     },
     waypoint::Waypoint,
-    write_set::{WriteOp, WriteSetMut},
+    write_set::WriteOp,
 };
 use diem_wallet::{io_utils, Mnemonic, WalletLibrary};
+use move_binary_format::CompiledModule;
+use move_core_types::language_storage::ModuleId;
 use num_traits::cast::{FromPrimitive, ToPrimitive};
 use reqwest::Url;
 use resource_viewer::{AnnotatedAccountStateBlob, MoveValueAnnotator, NullStateView};
@@ -55,6 +62,7 @@ use std::{
     path::{Path, PathBuf},
     process::Command,
     str::{self, FromStr},
+    sync::Arc,
     time,
 };
 
@@ -1121,7 +1129,7 @@ impl ClientProxy {
         match self.diem_root_account {
             Some(_) => self.association_transaction_with_local_diem_root_account(
                 TransactionPayload::WriteSet(WriteSetPayload::Direct(
-                    encode_stdlib_upgrade_transaction(),
+                    try_encode_stdlib_upgrade_transaction()?,
                 )),
                 is_blocking,
             ),
@@ -2100,21 +2108,135 @@ impl ClientProxy {
     }
 }
 
+/// The modules installed by a stdlib upgrade: the `current` release embedded at compile time with
+/// the `embedded-stdlib` feature, or the stdlib built from source otherwise.
+#[cfg(feature = "embedded-stdlib")]
+fn stdlib_upgrade_compiled_modules() -> &'static [CompiledModule] {
+    diem_framework_releases::current_modules()
+}
+
+#[cfg(not(feature = "embedded-stdlib"))]
+fn stdlib_upgrade_compiled_modules() -> &'static [CompiledModule] {
+    diem_framework::modules()
+}
+
+/// IDs of the modules written by a stdlib upgrade, for reviewers to diff against the modules that
+/// are currently deployed.
+pub fn stdlib_upgrade_modules() -> Vec<ModuleId> {
+    sorted_modules(stdlib_upgrade_compiled_modules())
+        .into_iter()
+        .map(|module| module.self_id())
+        .collect()
+}
+
+/// Modules in `ModuleId` order, so that the upgrade write set and its hash do not depend on the
+/// order in which the modules were loaded.
+fn sorted_modules(modules: &[CompiledModule]) -> Vec<&CompiledModule> {
+    let mut modules: Vec<_> = modules.iter().collect();
+    modules.sort_by_cached_key(|module| module.self_id());
+    modules
+}
+
+/// Every module is serialized into the same scratch buffer, which only grows to the size of the
+/// largest module, and then copied into an exactly sized vector.
+fn serialize_modules(
+    modules: &[CompiledModule],
+) -> Result<Vec<(AccessPath, Vec<u8>)>, BuilderError> {
+    let mut scratch = vec![];
+    sorted_modules(modules)
+        .into_iter()
+        .map(|module| {
+            scratch.clear();
+            module
+                .serialize(&mut scratch)
+                .map_err(|e| BuilderError::WriteSet(e.to_string()))?;
+            Ok((
+                AccessPath::code_access_path(module.self_id()),
+                scratch.clone(),
+            ))
+        })
+        .collect()
+}
+
+fn hash_stdlib_upgrade_modules(modules: &[(AccessPath, Vec<u8>)]) -> HashValue {
+    HashValue::sha3_256_of(&bcs::to_bytes(modules).expect("Modules are serializable"))
+}
+
+/// Builds stdlib upgrade write sets from modules that are serialized and hashed once, for servers
+/// encoding many upgrades. Clones share the serialized modules.
+#[derive(Clone, Debug)]
+pub struct UpgradeEncoder {
+    modules: Arc<[(AccessPath, Vec<u8>)]>,
+    hash: HashValue,
+}
+
+impl UpgradeEncoder {
+    /// Encoder installing the stdlib, see `stdlib_upgrade_modules`.
+    pub fn new() -> Result<Self, BuilderError> {
+        Self::from_modules(stdlib_upgrade_compiled_modules())
+    }
+
+    /// Encoder installing `modules`.
+    pub fn from_modules(modules: &[CompiledModule]) -> Result<Self, BuilderError> {
+        let modules = serialize_modules(modules)?;
+        let hash = hash_stdlib_upgrade_modules(&modules);
+        Ok(Self {
+            modules: modules.into(),
+            hash,
+        })
+    }
+
+    /// Hash of the installed modules, to be pinned with `encode_pinned`.
+    pub fn hash(&self) -> HashValue {
+        self.hash
+    }
+
+    pub fn encode(&self) -> Result<ChangeSet, BuilderError> {
+        let write_set = write_set_from_pairs(
+            self.modules
+                .iter()
+                .map(|(access_path, bytes)| (access_path.clone(), WriteOp::Value(bytes.clone())))
+                .collect(),
+        )?;
+        encode_changeset_with_events(write_set, vec![])
+    }
+
+    /// Like `encode`, but fails unless the modules hash to `expected_hash`, so that a signer never
+    /// installs a stdlib it did not expect.
+    pub fn encode_pinned(&self, expected_hash: HashValue) -> Result<ChangeSet, BuilderError> {
+        if self.hash != expected_hash {
+            return Err(BuilderError::StdlibHashMismatch {
+                expected: expected_hash,
+                got: self.hash,
+            });
+        }
+        self.encode()
+    }
+}
+
+/// Hash of the modules installed by a stdlib upgrade, to be pinned with
+/// `encode_stdlib_upgrade_transaction_pinned`.
+pub fn stdlib_upgrade_hash() -> Result<HashValue, BuilderError> {
+    Ok(UpgradeEncoder::new()?.hash())
+}
+
+/// Build the write set that replaces every module with the current stdlib, reporting
+/// serialization and write set failures instead of panicking.
+pub fn try_encode_stdlib_upgrade_transaction() -> Result<ChangeSet, BuilderError> {
+    UpgradeEncoder::new()?.encode()
+}
+
+/// Like `try_encode_stdlib_upgrade_transaction`, but fails unless the stdlib hashes to
+/// `expected_hash`, so that a signer never installs a stdlib it did not expect.
+pub fn encode_stdlib_upgrade_transaction_pinned(
+    expected_hash: HashValue,
+) -> Result<ChangeSet, BuilderError> {
+    UpgradeEncoder::new()?.encode_pinned(expected_hash)
+}
+
 // Update WriteSet
-fn encode_stdlib_upgrade_transaction() -> ChangeSet {
-    let mut write_set = WriteSetMut::new(vec![]);
-    for module in diem_framework::modules() {
-        let mut bytes = vec![];
-        module.serialize(&mut bytes).unwrap();
-        write_set.push((
-            AccessPath::code_access_path(module.self_id()),
-            WriteOp::Value(bytes),
-        ));
-    }
-    ChangeSet::new(
-        write_set.freeze().expect("Failed to create writeset"),
-        vec![],
-    )
+pub fn encode_stdlib_upgrade_transaction() -> ChangeSet {
+    try_encode_stdlib_upgrade_transaction().expect("Failed to create writeset")
 }
 
 fn parse_transaction_argument_for_client(s: &str) -> Result<TransactionArgument> {
@@ -2156,11 +2278,16 @@ impl fmt::Display for AccountEntry {
 
 #[cfg(test)]
 mod tests {
-    use crate::client_proxy::{parse_bool, AddressAndIndex, ClientProxy};
+    use crate::client_proxy::{
+        parse_bool, serialize_modules, sorted_modules, stdlib_upgrade_compiled_modules,
+        stdlib_upgrade_hash, stdlib_upgrade_modules, try_encode_stdlib_upgrade_transaction,
+        AddressAndIndex, ClientProxy, UpgradeEncoder,
+    };
+    use diem_crypto::HashValue;
     use diem_temppath::TempPath;
     use diem_types::{
-        chain_id::ChainId, ledger_info::LedgerInfo, on_chain_config::ValidatorSet,
-        waypoint::Waypoint,
+        access_path::AccessPath, chain_id::ChainId, ledger_info::LedgerInfo,
+        on_chain_config::ValidatorSet, waypoint::Waypoint, write_set::WriteOp,
     };
     use diem_wallet::io_utils;
     use proptest::prelude::*;
@@ -2308,6 +2435,80 @@ mod tests {
         assert_eq!(client.wallet.mnemonic(), wallet.mnemonic());
     }
 
+    #[test]
+    fn test_stdlib_upgrade_modules() {
+        let change_set = try_encode_stdlib_upgrade_transaction().unwrap();
+        let written: Vec<_> = change_set
+            .write_set()
+            .iter()
+            .map(|(access_path, _)| access_path.clone())
+            .collect();
+        let expected: Vec<_> = stdlib_upgrade_modules()
+            .into_iter()
+            .map(AccessPath::code_access_path)
+            .collect();
+        assert_eq!(written, expected);
+
+        let blobs: Vec<_> = change_set
+            .write_set()
+            .iter()
+            .map(|(_, op)| match op {
+                WriteOp::Value(bytes) => bytes.clone(),
+                WriteOp::Deletion => panic!("Stdlib upgrade deletes a module"),
+            })
+            .collect();
+        let expected_blobs: Vec<_> = sorted_modules(stdlib_upgrade_compiled_modules())
+            .into_iter()
+            .map(|module| {
+                let mut bytes = vec![];
+                module.serialize(&mut bytes).unwrap();
+                bytes
+            })
+            .collect();
+        assert_eq!(blobs, expected_blobs);
+    }
+
+    #[test]
+    fn test_serialize_modules_matches_fresh_buffers() {
+        let modules = stdlib_upgrade_compiled_modules();
+        let serialized = serialize_modules(modules).unwrap();
+        let sorted = sorted_modules(modules);
+        assert_eq!(serialized.len(), sorted.len());
+        for ((access_path, bytes), module) in serialized.iter().zip(sorted) {
+            let mut expected = vec![];
+            module.serialize(&mut expected).unwrap();
+            assert_eq!(*access_path, AccessPath::code_access_path(module.self_id()));
+            assert_eq!(*bytes, expected);
+        }
+    }
+
+    #[test]
+    fn test_upgrade_encoder() {
+        let encoder = UpgradeEncoder::new().unwrap();
+        assert_eq!(
+            encoder.encode().unwrap(),
+            try_encode_stdlib_upgrade_transaction().unwrap()
+        );
+        assert_eq!(encoder.hash(), stdlib_upgrade_hash().unwrap());
+        assert!(encoder.encode_pinned(encoder.hash()).is_ok());
+        assert!(encoder.encode_pinned(HashValue::zero()).is_err());
+    }
+
+    #[test]
+    fn test_upgrade_encoder_is_reproducible() {
+        let mut modules = stdlib_upgrade_compiled_modules().to_vec();
+        modules.reverse();
+        let reversed = UpgradeEncoder::from_modules(&modules).unwrap();
+        let encoder = UpgradeEncoder::new().unwrap();
+        assert_eq!(reversed.hash(), encoder.hash());
+        assert_eq!(
+            bcs::to_bytes(&reversed.encode().unwrap()).unwrap(),
+            bcs::to_bytes(&encoder.encode().unwrap()).unwrap()
+        );
+        let module_ids = stdlib_upgrade_modules();
+        assert!(module_ids.windows(2).all(|ids| ids[0] < ids[1]));
+    }
+
     proptest! {
         // Proptest is used to verify that the conversion will not panic with random input.
         #[test]
@@ -19,6 +19,7 @@ mod secure_storage_vault;
 mod state_sync;
 mod storage;
 mod transaction;
+mod transaction_builder;
 mod vm;
 
 static ALL_TARGETS: Lazy<BTreeMap<&'static str, Box<dyn FuzzTargetImpl>>> = Lazy::new(|| {
@@ -101,6 +102,8 @@ static ALL_TARGETS: Lazy<BTreeMap<&'static str, Box<dyn FuzzTargetImpl>>> = Lazy
         Box::new(transaction::SignedTransactionTarget::default()),
         Box::new(transaction::MutatedSignedTransaction::default()),
         Box::new(transaction::TwoSignedTransactions::default()),
+        // Transaction Builder
+        Box::new(transaction_builder::EncodeStdlibScript::default()),
         // VM
         Box::new(vm::CompiledModuleTarget::default()),
     ];
@@ -0,0 +1,52 @@
+// Copyright (c) The Diem Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{corpus_from_strategy, fuzz_data_to_value, FuzzTargetImpl};
+use diem_proptest_helpers::ValueGenerator;
+use diem_transaction_builder::stdlib_script::{encode_stdlib_script, StdlibScript};
+use diem_types::{account_address::AccountAddress, transaction::TransactionArgument};
+use move_core_types::language_storage::TypeTag;
+use proptest::{collection::vec, prelude::*, sample::select};
+
+/// Builds a `Script` for an arbitrary stdlib script from arbitrary type arguments and arguments,
+/// which `encode_stdlib_script` accepts without checking them against the script signature.
+#[derive(Clone, Debug, Default)]
+pub struct EncodeStdlibScript;
+
+impl FuzzTargetImpl for EncodeStdlibScript {
+    fn description(&self) -> &'static str {
+        "Transaction builder encode_stdlib_script + BCS serialization of the Script"
+    }
+
+    fn generate(&self, _idx: usize, _gen: &mut ValueGenerator) -> Option<Vec<u8>> {
+        Some(corpus_from_strategy(encode_stdlib_script_input()))
+    }
+
+    fn fuzz(&self, data: &[u8]) {
+        let (script, type_args, args) = fuzz_data_to_value(data, encode_stdlib_script_input());
+        let script = encode_stdlib_script(script, type_args, args);
+        bcs::to_bytes(&script).expect("serialization should work");
+    }
+}
+
+fn transaction_argument_strategy() -> impl Strategy<Value = TransactionArgument> {
+    prop_oneof![
+        any::<u8>().prop_map(TransactionArgument::U8),
+        any::<u64>().prop_map(TransactionArgument::U64),
+        any::<u128>().prop_map(TransactionArgument::U128),
+        any::<AccountAddress>().prop_map(TransactionArgument::Address),
+        vec(any::<u8>(), 0..256).prop_map(TransactionArgument::U8Vector),
+        vec(any::<AccountAddress>(), 0..8).prop_map(TransactionArgument::AddressVector),
+        any::<bool>().prop_map(TransactionArgument::Bool),
+    ]
+}
+
+prop_compose! {
+    fn encode_stdlib_script_input()(
+        script in select(StdlibScript::all()),
+        type_args in vec(any::<TypeTag>(), 0..4),
+        args in vec(transaction_argument_strategy(), 0..16),
+    ) -> (StdlibScript, Vec<TypeTag>, Vec<TransactionArgument>) {
+        (script, type_args, args)
+    }
+}
@@ -104,7 +104,7 @@ impl Fund for Coffer<'_> {
             } => {
                 let create_account_txn = treasury_compliance_account.sign_with_transaction_builder(
                     transaction_factory.create_parent_vasp_account(
-                        currency,
+                        currency.clone(),
                         0,
                         auth_key,
                         &format!("No. {} VASP", treasury_compliance_account.sequence_number()),
@@ -84,7 +84,7 @@ impl PublicUsageTest for FundAccount {
         let account = ctx.random_account();
         let amount = 1000;
         let currency = Currency::XUS;
-        ctx.fund(currency, account.authentication_key(), amount)?;
+        ctx.fund(currency.clone(), account.authentication_key(), amount)?;
         check_account_balance(&client, currency, account.address(), amount)?;
 
         Ok(())
@@ -106,20 +106,20 @@ impl PublicUsageTest for TransferCoins {
         let amount = 1000;
         let currency = Currency::XUS;
         let client = ctx.client();
-        ctx.fund(currency, account.authentication_key(), amount)?;
+        ctx.fund(currency.clone(), account.authentication_key(), amount)?;
 
         let mut payer = ctx.random_account();
         let payee = ctx.random_account();
         let create_payer =
             account.sign_with_transaction_builder(ctx.tx_factory().create_child_vasp_account(
-                currency,
+                currency.clone(),
                 payer.authentication_key(),
                 false,
                 100,
             ));
         let create_payee =
             account.sign_with_transaction_builder(ctx.tx_factory().create_child_vasp_account(
-                currency,
+                currency.clone(),
                 payee.authentication_key(),
                 false,
                 0,
@@ -131,11 +131,11 @@ impl PublicUsageTest for TransferCoins {
         client.batch(batch)?;
         client.wait_for_signed_transaction(&create_payer, None, None)?;
         client.wait_for_signed_transaction(&create_payee, None, None)?;
-        check_account_balance(&client, currency, payer.address(), 100)?;
+        check_account_balance(&client, currency.clone(), payer.address(), 100)?;
 
-        ctx.transfer_coins(currency, &mut payer, payee.address(), 10)?;
-        check_account_balance(&client, currency, payer.address(), 90)?;
-        check_account_balance(&client, currency, payee.address(), 10)?;
+        ctx.transfer_coins(currency.clone(), &mut payer, payee.address(), 10)?;
+        check_account_balance(&client, currency.clone(), payer.address(), 90)?;
+        check_account_balance(&client, currency.clone(), payee.address(), 10)?;
         let account_view = client.get_account(payee.address())?.into_inner().unwrap();
         let balance = account_view
             .balances